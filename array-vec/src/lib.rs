@@ -1,12 +1,59 @@
-#![feature(const_generics, specialization)]
-
+use std::borrow::{Borrow, BorrowMut};
+use std::cmp::Ordering;
 use std::convert::TryInto;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::iter::{FromIterator, Peekable};
 use std::mem::{ManuallyDrop, MaybeUninit};
-use std::ops::{Deref, DerefMut};
+use std::ops::{Bound, Deref, DerefMut, Range, RangeBounds};
 
 #[cfg(test)]
 mod test;
 
+#[cfg(feature = "serde")]
+mod serde_impl;
+
+mod string;
+pub use string::ArrayString;
+
+/// Drops the elements of `ptr` in `range`
+///
+/// Safety: the elements in `range` must be initialized, and must not be used after this
+unsafe fn drop_range<T>(ptr: *mut T, range: Range<usize>) {
+    std::ptr::drop_in_place(std::ptr::slice_from_raw_parts_mut(
+        ptr.add(range.start),
+        range.end - range.start,
+    ))
+}
+
+/// The error returned when there are too many elements to fit in an `ArrayVec` or `ArrayString`
+///
+/// This gives back whatever didn't fit, usually as an iterator
+pub struct CapacityError<I> {
+    rest: I,
+}
+
+impl<I> CapacityError<I> {
+    /// The elements that didn't fit
+    pub fn into_inner(self) -> I {
+        self.rest
+    }
+}
+
+impl<I> fmt::Debug for CapacityError<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CapacityError").finish_non_exhaustive()
+    }
+}
+
+impl<I> fmt::Display for CapacityError<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("there are too many elements to fit")
+    }
+}
+
+impl<I> std::error::Error for CapacityError<I> {}
 
 /// An array backed fixed capcity vector
 pub struct ArrayVec<T, const N: usize> {
@@ -60,6 +107,43 @@ impl<T, const N: usize> ArrayVec<T, { N }> {
         }
     }
 
+    /// Pushes the elements of `iter` onto the end of the `ArrayVec`
+    ///
+    /// If they don't all fit, the `ArrayVec` is filled and the rest of the elements are given back in the error
+    pub fn try_extend<I: IntoIterator<Item = T>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), CapacityError<Peekable<I::IntoIter>>> {
+        let mut iter = iter.into_iter().peekable();
+
+        while self.len < N {
+            match iter.next() {
+                Some(value) => {
+                    let _ = self.push(value);
+                }
+                None => return Ok(()),
+            }
+        }
+
+        match iter.peek() {
+            Some(_) => Err(CapacityError { rest: iter }),
+            None => Ok(()),
+        }
+    }
+
+    /// Collects the elements of `iter` into an `ArrayVec`
+    ///
+    /// If they don't all fit, the rest of the elements are given back in the error
+    pub fn try_from_iter<I: IntoIterator<Item = T>>(
+        iter: I,
+    ) -> Result<Self, CapacityError<Peekable<I::IntoIter>>> {
+        let mut arr = Self::default();
+
+        arr.try_extend(iter)?;
+
+        Ok(arr)
+    }
+
     /// Removes the last element from the `ArrayVec`
     pub fn pop(&mut self) -> Option<T> {
         if N == 0 {
@@ -70,20 +154,316 @@ impl<T, const N: usize> ArrayVec<T, { N }> {
         unsafe { Some(self.as_ptr().add(self.len).read()) }
     }
 
+    /// Inserts an element at `index`, shifting all elements after it to the right
+    ///
+    /// If the `ArrayVec` is full, the element is given back
+    ///
+    /// Panics if `index > self.len()`
+    pub fn insert(&mut self, index: usize, value: T) -> Result<(), T> {
+        assert!(index <= self.len, "insertion index out of bounds!");
+
+        if self.len == N {
+            return Err(value);
+        }
+
+        unsafe {
+            let ptr = self.as_mut_ptr().add(index);
+
+            std::ptr::copy(ptr, ptr.add(1), self.len - index);
+            ptr.write(value);
+        }
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Removes the element at `index`, shifting all elements after it to the left
+    ///
+    /// Panics if `index >= self.len()`
+    pub fn remove(&mut self, index: usize) -> T {
+        assert!(index < self.len, "removal index out of bounds!");
+
+        unsafe {
+            let ptr = self.as_mut_ptr().add(index);
+            let value = ptr.read();
+
+            std::ptr::copy(ptr.add(1), ptr, self.len - index - 1);
+            self.len -= 1;
+
+            value
+        }
+    }
+
+    /// Removes the element at `index`, and replaces it with the last element
+    ///
+    /// This doesn't preserve the order of the elements, but it doesn't need to shift them
+    ///
+    /// Panics if `index >= self.len()`
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        assert!(index < self.len, "removal index out of bounds!");
+
+        self.len -= 1;
+
+        unsafe {
+            let ptr = self.as_mut_ptr();
+            let value = ptr.add(index).read();
+
+            std::ptr::copy(ptr.add(self.len), ptr.add(index), 1);
+
+            value
+        }
+    }
+
+    /// Drops every element after the first `len`, if there are more than `len` elements
+    ///
+    /// If dropping an element panics, the rest of the elements are still dropped
+    /// and the `ArrayVec` is left with `len` elements
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len {
+            return;
+        }
+
+        let end = self.len;
+
+        // the length is reset first, so a panic while dropping can't lead to a double drop
+        self.len = len;
+
+        unsafe {
+            drop_range(self.as_mut_ptr(), len..end);
+        }
+    }
+
+    /// Keeps only the elements where `f` returns true, in their original order
+    ///
+    /// If `f` or dropping an element panics, the elements that weren't visited yet are kept
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        self.retain_mut(|x| f(x))
+    }
+
+    /// Keeps only the elements where `f` returns true, in their original order
+    ///
+    /// If `f` or dropping an element panics, the elements that weren't visited yet are kept
+    pub fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, mut f: F) {
+        /// Closes the gap left by the removed elements, even if `f` panics
+        struct Guard<'a, T, const N: usize> {
+            arr: &'a mut ArrayVec<T, { N }>,
+            len: usize,
+            visited: usize,
+            removed: usize,
+        }
+
+        impl<T, const N: usize> Drop for Guard<'_, T, { N }> {
+            fn drop(&mut self) {
+                unsafe {
+                    let ptr = self.arr.as_mut_ptr();
+
+                    std::ptr::copy(
+                        ptr.add(self.visited),
+                        ptr.add(self.visited - self.removed),
+                        self.len - self.visited,
+                    );
+                }
+
+                self.arr.len = self.len - self.removed;
+            }
+        }
+
+        let len = self.len;
+
+        // the elements are only tracked by the guard until it's done,
+        // so a panic can't expose a moved or dropped element
+        self.len = 0;
+
+        let mut guard = Guard {
+            arr: self,
+            len,
+            visited: 0,
+            removed: 0,
+        };
+
+        while guard.visited < len {
+            let ptr = unsafe { guard.arr.as_mut_ptr().add(guard.visited) };
+
+            if f(unsafe { &mut *ptr }) {
+                if guard.removed != 0 {
+                    unsafe {
+                        std::ptr::copy_nonoverlapping(ptr, ptr.sub(guard.removed), 1);
+                    }
+                }
+
+                guard.visited += 1;
+            } else {
+                guard.visited += 1;
+                guard.removed += 1;
+
+                unsafe {
+                    std::ptr::drop_in_place(ptr);
+                }
+            }
+        }
+    }
+
+    /// Removes all but the first of consecutive elements that have the same key
+    ///
+    /// The key is calculated once for every element
+    pub fn dedup_by_key<K: PartialEq, F: FnMut(&mut T) -> K>(&mut self, mut key: F) {
+        let mut last = None;
+
+        self.retain_mut(|x| {
+            let key = key(x);
+            let keep = last.as_ref() != Some(&key);
+
+            last = Some(key);
+
+            keep
+        })
+    }
+
+    /// Converts `range` to the range of indices it covers
+    ///
+    /// Panics if the range is decreasing or goes past the end of the `ArrayVec`
+    fn range<R: RangeBounds<usize>>(&self, range: R) -> Range<usize> {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.checked_add(1).expect("range out of bounds!"),
+            Bound::Unbounded => 0,
+        };
+
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.checked_add(1).expect("range out of bounds!"),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.len,
+        };
+
+        assert!(start <= end && end <= self.len, "range out of bounds!");
+
+        start..end
+    }
+
+    /// Removes the elements in `range`, and iterates over them
+    ///
+    /// The elements after the range are moved back when the `Drain` is dropped,
+    /// any elements that weren't iterated over are dropped then.
+    /// If the `Drain` is leaked, the elements after the range are leaked too
+    ///
+    /// Panics if the range is decreasing or goes past the end of the `ArrayVec`
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T, { N }> {
+        let Range { start, end } = self.range(range);
+        let tail_len = self.len - end;
+
+        // the drained elements and the tail are owned by the `Drain` until it is dropped
+        self.len = start;
+
+        Drain {
+            arr: self,
+            rest: Cursor { start, end },
+            tail: end,
+            tail_len,
+        }
+    }
+
+    /// Replaces the elements in `range` with the elements of `replace_with`,
+    /// and iterates over the removed elements
+    ///
+    /// If the replacement doesn't fit, the `ArrayVec` is left unchanged and the replacement is given back in the error.
+    /// The capacity is checked with `ExactSizeIterator::len`, if the replacement yields more elements
+    /// than that the rest are left in the iterator, and if it yields fewer the gap is closed
+    ///
+    /// Panics if the range is decreasing or goes past the end of the `ArrayVec`
+    pub fn splice<R, I>(
+        &mut self,
+        range: R,
+        replace_with: I,
+    ) -> Result<IntoIter<T, { N }>, CapacityError<I::IntoIter>>
+    where
+        R: RangeBounds<usize>,
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let range = self.range(range);
+        let mut replace_with = replace_with.into_iter();
+        let count = replace_with.len();
+
+        if count > N - self.len + (range.end - range.start) {
+            return Err(CapacityError { rest: replace_with });
+        }
+
+        let start = range.start;
+        let removed = self.drain(range).collect::<ArrayVec<T, { N }>>();
+
+        /// Closes the gap between the written elements and the tail, even if the replacement panics
+        struct Guard<'a, T, const N: usize> {
+            arr: &'a mut ArrayVec<T, { N }>,
+            written: usize,
+            tail: usize,
+            tail_len: usize,
+        }
+
+        impl<T, const N: usize> Drop for Guard<'_, T, { N }> {
+            fn drop(&mut self) {
+                unsafe {
+                    let ptr = self.arr.as_mut_ptr();
+
+                    std::ptr::copy(ptr.add(self.tail), ptr.add(self.written), self.tail_len);
+                }
+
+                self.arr.len = self.written + self.tail_len;
+            }
+        }
+
+        let tail_len = self.len - start;
+
+        // the tail is moved out of the way first, and only the guard tracks it while the
+        // replacement is written
+        self.len = start;
+
+        unsafe {
+            let ptr = self.as_mut_ptr().add(start);
+
+            std::ptr::copy(ptr, ptr.add(count), tail_len);
+        }
+
+        let mut guard = Guard {
+            arr: self,
+            written: start,
+            tail: start + count,
+            tail_len,
+        };
+
+        for value in replace_with.by_ref().take(count) {
+            unsafe {
+                guard.arr.as_mut_ptr().add(guard.written).write(value);
+            }
+
+            guard.written += 1;
+        }
+
+        Ok(removed.into_iter())
+    }
+
     /// The length of the `ArrayVec`
     pub fn len(&self) -> usize {
         self.len
     }
 
+    /// Checks if the `ArrayVec` has no elements
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
     /// Removes all elements from the `ArrayVec`
+    ///
+    /// If dropping an element panics, the rest of the elements are still dropped
+    /// and the `ArrayVec` is left empty
     pub fn clear(&mut self) {
-        unsafe {
-            std::ptr::drop_in_place(self.as_mut_slice());
-        }
-        self.len = 0;
+        self.truncate(0)
     }
 
-    /// Convert to an array, not checked in release mode and will panic in debug mode
+    /// Convert to an array
+    ///
+    /// # Safety
+    ///
+    /// The `ArrayVec` must be full, this is not checked in release mode and will panic in debug mode
     pub unsafe fn into_array_unchecked(mut self) -> [T; N] {
         debug_assert_eq!(self.len, N);
         self.len = 0;
@@ -92,22 +472,8 @@ impl<T, const N: usize> ArrayVec<T, { N }> {
 }
 
 impl<T: Clone, const N: usize> Clone for ArrayVec<T, { N }> {
-    default fn clone(&self) -> Self {
-        self.iter().cloned().collect()
-    }
-}
-
-impl<T: Copy, const N: usize> Clone for ArrayVec<T, { N }> {
     fn clone(&self) -> Self {
-        let mut arr = ArrayVec::<T, { N }>::default();
-
-        unsafe {
-            std::ptr::copy_nonoverlapping(self.as_ptr(), arr.as_mut_ptr(), self.len);
-
-            arr.len = self.len;
-        }
-
-        arr
+        self.iter().cloned().collect()
     }
 }
 
@@ -131,6 +497,105 @@ impl<T, const N: usize> Drop for ArrayVec<T, { N }> {
     }
 }
 
+impl<T, const N: usize> AsRef<[T]> for ArrayVec<T, { N }> {
+    fn as_ref(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T, const N: usize> AsMut<[T]> for ArrayVec<T, { N }> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl<T, const N: usize> Borrow<[T]> for ArrayVec<T, { N }> {
+    fn borrow(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T, const N: usize> BorrowMut<[T]> for ArrayVec<T, { N }> {
+    fn borrow_mut(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for ArrayVec<T, { N }> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_slice(), f)
+    }
+}
+
+impl<T: Hash, const N: usize> Hash for ArrayVec<T, { N }> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state)
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize, const M: usize> PartialEq<ArrayVec<U, { M }>>
+    for ArrayVec<T, { N }>
+{
+    fn eq(&self, other: &ArrayVec<U, { M }>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize> PartialEq<[U]> for ArrayVec<T, { N }> {
+    fn eq(&self, other: &[U]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize> PartialEq<&[U]> for ArrayVec<T, { N }> {
+    fn eq(&self, other: &&[U]) -> bool {
+        self.as_slice() == *other
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize, const K: usize> PartialEq<[U; K]> for ArrayVec<T, { N }> {
+    fn eq(&self, other: &[U; K]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize> PartialEq<Vec<U>> for ArrayVec<T, { N }> {
+    fn eq(&self, other: &Vec<U>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for ArrayVec<T, { N }> {}
+
+impl<T: PartialOrd, const N: usize, const M: usize> PartialOrd<ArrayVec<T, { M }>>
+    for ArrayVec<T, { N }>
+{
+    fn partial_cmp(&self, other: &ArrayVec<T, { M }>) -> Option<Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<T: Ord, const N: usize> Ord for ArrayVec<T, { N }> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+/// Writes as many bytes as fit, a full `ArrayVec` accepts none
+impl<const N: usize> io::Write for ArrayVec<u8, { N }> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let len = buf.len().min(N - self.len);
+
+        self.extend(buf[..len].iter().copied());
+
+        Ok(len)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
 impl<T, const N: usize> From<[T; N]> for ArrayVec<T, { N }> {
     fn from(arr: [T; N]) -> Self {
         Self {
@@ -158,128 +623,181 @@ impl<T, const N: usize> IntoIterator for ArrayVec<T, { N }> {
 
     fn into_iter(self) -> Self::IntoIter {
         IntoIter {
+            rest: Cursor {
+                start: 0,
+                end: self.len,
+            },
             arr: ManuallyDrop::new(self),
-            idx: 0,
         }
     }
 }
 
-/// An iterator over the elements of a `ArrayVec`
-pub struct IntoIter<T, const N: usize> {
-    arr: ManuallyDrop<ArrayVec<T, { N }>>,
-    idx: usize,
-}
+impl<'a, T, const N: usize> IntoIterator for &'a ArrayVec<T, { N }> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
 
-impl<T: Clone, const N: usize> Clone for IntoIter<T, { N }> {
-    default fn clone(&self) -> Self {
-        IntoIter {
-            arr: ManuallyDrop::new(self.arr[self.idx..].iter().cloned().collect()),
-            idx: 0,
-        }
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
     }
 }
 
-impl<T: Copy, const N: usize> Clone for IntoIter<T, { N }> {
-    fn clone(&self) -> Self {
-        let mut arr = ArrayVec::<T, { N }>::default();
-
-        unsafe {
-            std::ptr::copy_nonoverlapping(
-                self.arr.as_ptr().add(self.idx),
-                arr.as_mut_ptr(),
-                self.arr.len - self.idx,
-            );
-
-            arr.len = self.arr.len - self.idx;
-        }
+impl<'a, T, const N: usize> IntoIterator for &'a mut ArrayVec<T, { N }> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
 
-        IntoIter {
-            arr: ManuallyDrop::new(arr),
-            idx: 0,
-        }
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
     }
 }
 
-impl<T, const N: usize> Iterator for IntoIter<T, { N }> {
-    type Item = T;
+/// The elements that are left in an `IntoIter` or a `Drain`, which are in `start..end` of the array
+///
+/// Safety: every method that takes `ptr` must be given a pointer to the start of that array
+struct Cursor {
+    start: usize,
+    end: usize,
+}
 
-    fn next(&mut self) -> Option<T> {
-        if N == 0 || self.arr.len == self.idx {
+impl Cursor {
+    fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    unsafe fn next<T>(&mut self, ptr: *const T) -> Option<T> {
+        if self.start == self.end {
             None
         } else {
-            let output = unsafe { self.arr.as_ptr().add(self.idx).read() };
+            let output = ptr.add(self.start).read();
 
-            self.idx += 1;
+            self.start += 1;
 
             Some(output)
         }
     }
 
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        let size = self.arr.len - self.idx;
+    unsafe fn next_back<T>(&mut self, ptr: *const T) -> Option<T> {
+        if self.start == self.end {
+            None
+        } else {
+            self.end -= 1;
 
-        (size, Some(size))
+            Some(ptr.add(self.end).read())
+        }
     }
 
-    fn nth(&mut self, n: usize) -> Option<T> {
-        match self.idx.checked_add(n) {
-            Some(idx) if idx < self.arr.len => {
-                unsafe {
-                    std::ptr::drop_in_place(&mut self.arr[self.idx..idx]);
-                }
-                self.idx = idx;
-                self.next()
+    /// Drops the elements in `self.start..start`
+    ///
+    /// The elements are forgotten before they are dropped,
+    /// so a panic while dropping can't lead to a double drop
+    ///
+    /// Safety: `start` must be in `self.start..=self.end`
+    unsafe fn forget_front<T>(&mut self, ptr: *mut T, start: usize) {
+        let old_start = self.start;
+
+        self.start = start;
+
+        drop_range(ptr, old_start..start)
+    }
+
+    /// Drops the elements in `end..self.end`, like `forget_front`
+    ///
+    /// Safety: `end` must be in `self.start..=self.end`
+    unsafe fn forget_back<T>(&mut self, ptr: *mut T, end: usize) {
+        let old_end = self.end;
+
+        self.end = end;
+
+        drop_range(ptr, end..old_end)
+    }
+
+    unsafe fn nth<T>(&mut self, ptr: *mut T, n: usize) -> Option<T> {
+        match self.start.checked_add(n) {
+            Some(start) if start < self.end => {
+                self.forget_front(ptr, start);
+                self.next(ptr)
             }
             _ => {
-                unsafe {
-                    std::ptr::drop_in_place(&mut self.arr[self.idx..]);
-                }
-                self.idx = self.arr.len;
+                self.forget_front(ptr, self.end);
+                None
+            }
+        }
+    }
+
+    unsafe fn nth_back<T>(&mut self, ptr: *mut T, n: usize) -> Option<T> {
+        match self.end.checked_sub(n) {
+            Some(end) if self.start < end => {
+                self.forget_back(ptr, end);
+                self.next_back(ptr)
+            }
+            _ => {
+                self.forget_front(ptr, self.end);
                 None
             }
         }
     }
 }
 
+/// An iterator over the elements of a `ArrayVec`
+pub struct IntoIter<T, const N: usize> {
+    arr: ManuallyDrop<ArrayVec<T, { N }>>,
+    rest: Cursor,
+}
+
+impl<T: Clone, const N: usize> Clone for IntoIter<T, { N }> {
+    fn clone(&self) -> Self {
+        let arr: ArrayVec<T, { N }> = self.arr[self.rest.start..self.rest.end]
+            .iter()
+            .cloned()
+            .collect();
+
+        arr.into_iter()
+    }
+}
+
+impl<T, const N: usize> Iterator for IntoIter<T, { N }> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        unsafe { self.rest.next(self.arr.as_ptr()) }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let size = self.rest.len();
+
+        (size, Some(size))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<T> {
+        unsafe { self.rest.nth(self.arr.as_mut_ptr(), n) }
+    }
+}
+
 impl<T, const N: usize> ExactSizeIterator for IntoIter<T, { N }> {}
 impl<T, const N: usize> std::iter::FusedIterator for IntoIter<T, { N }> {}
 
 impl<T, const N: usize> DoubleEndedIterator for IntoIter<T, { N }> {
     fn next_back(&mut self) -> Option<T> {
-        if self.idx == self.arr.len {
-            None
-        } else {
-            self.arr.pop()
-        }
+        unsafe { self.rest.next_back(self.arr.as_ptr()) }
     }
 
     fn nth_back(&mut self, n: usize) -> Option<T> {
-        match self.arr.len.checked_sub(n) {
-            Some(len) if self.idx < len => {
-                unsafe {
-                    std::ptr::drop_in_place(&mut self.arr[len..]);
-                }
-                self.arr.len = len;
-                self.next_back()
-            }
-            _ => {
-                unsafe {
-                    std::ptr::drop_in_place(&mut self.arr[self.idx..]);
-                }
-                self.idx = self.arr.len;
-                None
-            }
-        }
+        unsafe { self.rest.nth_back(self.arr.as_mut_ptr(), n) }
     }
 }
 
+/// Stops once the `ArrayVec` is full, and the rest of the elements are ignored
+///
+/// Use `try_extend` to find out if there were too many elements
 impl<T, const N: usize> Extend<T> for ArrayVec<T, { N }> {
     fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
         let _ = iter.into_iter().take(N).try_fold((), |_, x| self.push(x));
     }
 }
 
-impl<T, const N: usize> std::iter::FromIterator<T> for ArrayVec<T, { N }> {
+/// Stops once the `ArrayVec` is full, and the rest of the elements are ignored
+///
+/// Use `try_from_iter` or collect into `Strict` to find out if there were too many elements
+impl<T, const N: usize> FromIterator<T> for ArrayVec<T, { N }> {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
         let mut arr = ArrayVec::<T, { N }>::default();
 
@@ -289,13 +807,110 @@ impl<T, const N: usize> std::iter::FromIterator<T> for ArrayVec<T, { N }> {
     }
 }
 
+/// A wrapper to collect into that panics if there are too many elements, instead of ignoring them
+///
+/// ```rust
+/// # use array_vec::{ArrayVec, Strict};
+/// let Strict(arr) = (0..3).collect::<Strict<ArrayVec<i32, 4>>>();
+///
+/// assert_eq!(arr.len(), 3);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Strict<A>(pub A);
+
+impl<A> Strict<A> {
+    /// The wrapped collection
+    pub fn into_inner(self) -> A {
+        self.0
+    }
+}
+
+/// Panics if there are more than `N` elements
+impl<T, const N: usize> FromIterator<T> for Strict<ArrayVec<T, { N }>> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        match ArrayVec::try_from_iter(iter) {
+            Ok(arr) => Strict(arr),
+            Err(_) => panic!("collected more than {} elements into an ArrayVec", N),
+        }
+    }
+}
+
 impl<T, const N: usize> Drop for IntoIter<T, { N }> {
     fn drop(&mut self) {
+        unsafe { self.rest.forget_front(self.arr.as_mut_ptr(), self.rest.end) }
+    }
+}
+
+/// An iterator that removes a range of elements from an `ArrayVec`
+///
+/// You can make one with `ArrayVec::drain`
+pub struct Drain<'a, T, const N: usize> {
+    arr: &'a mut ArrayVec<T, { N }>,
+    /// The drained elements that are left
+    rest: Cursor,
+    /// The elements after the drained range are in `tail..tail + tail_len`
+    tail: usize,
+    tail_len: usize,
+}
+
+impl<T, const N: usize> Iterator for Drain<'_, T, { N }> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        unsafe { self.rest.next(self.arr.as_ptr()) }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let size = self.rest.len();
+
+        (size, Some(size))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<T> {
+        unsafe { self.rest.nth(self.arr.as_mut_ptr(), n) }
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for Drain<'_, T, { N }> {}
+impl<T, const N: usize> std::iter::FusedIterator for Drain<'_, T, { N }> {}
+
+impl<T, const N: usize> DoubleEndedIterator for Drain<'_, T, { N }> {
+    fn next_back(&mut self) -> Option<T> {
+        unsafe { self.rest.next_back(self.arr.as_ptr()) }
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<T> {
+        unsafe { self.rest.nth_back(self.arr.as_mut_ptr(), n) }
+    }
+}
+
+impl<T, const N: usize> Drop for Drain<'_, T, { N }> {
+    fn drop(&mut self) {
+        /// Moves the tail back, even if dropping one of the drained elements panics
+        struct Guard<'r, 'a, T, const N: usize>(&'r mut Drain<'a, T, { N }>);
+
+        impl<T, const N: usize> Drop for Guard<'_, '_, T, { N }> {
+            fn drop(&mut self) {
+                let drain = &mut *self.0;
+                let len = drain.arr.len;
+
+                unsafe {
+                    let ptr = drain.arr.as_mut_ptr();
+
+                    std::ptr::copy(ptr.add(drain.tail), ptr.add(len), drain.tail_len);
+                }
+
+                drain.arr.len = len + drain.tail_len;
+            }
+        }
+
+        let guard = Guard(self);
+
         unsafe {
-            std::ptr::drop_in_place(std::slice::from_raw_parts_mut(
-                self.arr.as_mut_ptr().add(self.idx),
-                self.arr.len - self.idx,
-            ))
+            let drain = &mut *guard.0;
+            let end = drain.rest.end;
+
+            drain.rest.forget_front(drain.arr.as_mut_ptr(), end);
         }
     }
 }
@@ -0,0 +1,50 @@
+use crate::ArrayVec;
+
+use serde::de::{Deserialize, Deserializer, Error, IgnoredAny, SeqAccess, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use std::fmt;
+use std::marker::PhantomData;
+
+/// Serializes the elements as a sequence
+impl<T: Serialize, const N: usize> Serialize for ArrayVec<T, { N }> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+/// Deserializes a sequence of at most `N` elements, longer sequences are an error
+impl<'de, T: Deserialize<'de>, const N: usize> Deserialize<'de> for ArrayVec<T, { N }> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(ArrayVecVisitor(PhantomData))
+    }
+}
+
+struct ArrayVecVisitor<T, const N: usize>(PhantomData<T>);
+
+impl<'de, T: Deserialize<'de>, const N: usize> Visitor<'de> for ArrayVecVisitor<T, { N }> {
+    type Value = ArrayVec<T, { N }>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a sequence of at most {} elements", N)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut arr = ArrayVec::default();
+
+        while let Some(value) = seq.next_element()? {
+            if arr.push(value).is_err() {
+                // count the rest so the error says how long the sequence really is
+                let mut len = N + 1;
+
+                while seq.next_element::<IgnoredAny>()?.is_some() {
+                    len += 1;
+                }
+
+                return Err(A::Error::invalid_length(len, &self));
+            }
+        }
+
+        Ok(arr)
+    }
+}
@@ -0,0 +1,203 @@
+use crate::{ArrayVec, CapacityError};
+
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::{Deref, DerefMut};
+use std::str::FromStr;
+
+/// An array backed fixed capacity string, that holds at most `N` bytes of UTF-8
+#[derive(Clone, Default)]
+pub struct ArrayString<const N: usize> {
+    vec: ArrayVec<u8, { N }>,
+}
+
+impl<const N: usize> ArrayString<{ N }> {
+    /// Creates a new empty string
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// As a shared reference to a `str`
+    pub fn as_str(&self) -> &str {
+        self
+    }
+
+    /// As a unique reference to a `str`
+    pub fn as_mut_str(&mut self) -> &mut str {
+        self
+    }
+
+    /// The length of the string in bytes
+    pub fn len(&self) -> usize {
+        self.vec.len()
+    }
+
+    /// Checks if the string is empty
+    pub fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
+
+    /// Appends `s` to the end of the string
+    ///
+    /// If it doesn't fit, the string is left unchanged and `s` is given back in the error
+    pub fn try_push_str<'a>(&mut self, s: &'a str) -> Result<(), CapacityError<&'a str>> {
+        if s.len() > N - self.len() {
+            return Err(CapacityError { rest: s });
+        }
+
+        self.vec.extend(s.bytes());
+
+        Ok(())
+    }
+
+    /// Appends `s` to the end of the string
+    ///
+    /// Panics if it doesn't fit
+    pub fn push_str(&mut self, s: &str) {
+        self.try_push_str(s).expect("the ArrayString is full!")
+    }
+
+    /// Appends `c` to the end of the string
+    ///
+    /// If it doesn't fit, the string is left unchanged and `c` is given back in the error
+    pub fn try_push(&mut self, c: char) -> Result<(), CapacityError<char>> {
+        self.try_push_str(c.encode_utf8(&mut [0; 4]))
+            .map_err(|_| CapacityError { rest: c })
+    }
+
+    /// Appends `c` to the end of the string
+    ///
+    /// Panics if it doesn't fit
+    pub fn push(&mut self, c: char) {
+        self.try_push(c).expect("the ArrayString is full!")
+    }
+
+    /// Removes the last `char` from the string
+    pub fn pop(&mut self) -> Option<char> {
+        let c = self.chars().next_back()?;
+
+        self.vec.truncate(self.len() - c.len_utf8());
+
+        Some(c)
+    }
+
+    /// Shortens the string to `len` bytes, if it is longer than that
+    ///
+    /// Panics if `len` is not on a `char` boundary
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len() {
+            assert!(self.is_char_boundary(len), "truncated inside of a char!");
+
+            self.vec.truncate(len);
+        }
+    }
+
+    /// Removes everything from the string
+    pub fn clear(&mut self) {
+        self.vec.clear()
+    }
+}
+
+impl<const N: usize> Deref for ArrayString<{ N }> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        // only whole `str`s are ever added, and only whole `char`s are ever removed
+        unsafe { std::str::from_utf8_unchecked(&self.vec) }
+    }
+}
+
+impl<const N: usize> DerefMut for ArrayString<{ N }> {
+    fn deref_mut(&mut self) -> &mut str {
+        unsafe { std::str::from_utf8_unchecked_mut(&mut self.vec) }
+    }
+}
+
+impl<const N: usize> AsRef<str> for ArrayString<{ N }> {
+    fn as_ref(&self) -> &str {
+        self
+    }
+}
+
+impl<const N: usize> Borrow<str> for ArrayString<{ N }> {
+    fn borrow(&self) -> &str {
+        self
+    }
+}
+
+impl<const N: usize> fmt::Write for ArrayString<{ N }> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.try_push_str(s).map_err(|_| fmt::Error)
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.try_push(c).map_err(|_| fmt::Error)
+    }
+}
+
+/// Fails if the string is longer than `N` bytes
+impl<const N: usize> FromStr for ArrayString<{ N }> {
+    type Err = CapacityError<()>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut string = Self::new();
+
+        string
+            .try_push_str(s)
+            .map_err(|_| CapacityError { rest: () })?;
+
+        Ok(string)
+    }
+}
+
+impl<const N: usize> fmt::Debug for ArrayString<{ N }> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize> fmt::Display for ArrayString<{ N }> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize> Hash for ArrayString<{ N }> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl<const N: usize, const M: usize> PartialEq<ArrayString<{ M }>> for ArrayString<{ N }> {
+    fn eq(&self, other: &ArrayString<{ M }>) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<const N: usize> Eq for ArrayString<{ N }> {}
+
+impl<const N: usize> PartialEq<str> for ArrayString<{ N }> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for ArrayString<{ N }> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<const N: usize, const M: usize> PartialOrd<ArrayString<{ M }>> for ArrayString<{ N }> {
+    fn partial_cmp(&self, other: &ArrayString<{ M }>) -> Option<Ordering> {
+        self.as_str().partial_cmp(other.as_str())
+    }
+}
+
+impl<const N: usize> Ord for ArrayString<{ N }> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
@@ -1,8 +1,14 @@
-use super::{ArrayVec, IntoIter};
+#![allow(clippy::iter_nth_zero)]
+
+use super::{ArrayString, ArrayVec, Strict};
+
+use std::cell::Cell;
+use std::iter::FromIterator;
+use std::panic::{catch_unwind, AssertUnwindSafe};
 
 #[test]
 fn iter() {
-    let a = ArrayVec::<_, { 5 }>::from([10, 20, 30, 40, 50]);
+    let a = ArrayVec::<_, 5>::from([10, 20, 30, 40, 50]);
 
     let mut iter = a.clone().into_iter();
 
@@ -18,3 +24,509 @@ fn iter() {
     assert_eq!(iter.nth_back(0), Some(40));
     assert_eq!(iter.nth_back(2), Some(10));
 }
+
+#[test]
+fn clone() {
+    let a = ArrayVec::<_, 4>::from([1, 2, 3, 4]);
+
+    assert_eq!(a.clone().as_slice(), &[1, 2, 3, 4]);
+
+    let mut a = ArrayVec::<String, 4>::default();
+
+    a.extend(vec!["a".to_string(), "b".to_string(), "c".to_string()]);
+
+    let b = a.clone();
+
+    assert_eq!(b.len(), 3);
+    assert_eq!(a.as_slice(), b.as_slice());
+
+    let mut iter = b.into_iter();
+
+    assert_eq!(iter.next().as_deref(), Some("a"));
+
+    let rest = iter.clone();
+
+    assert!(iter.eq(rest));
+}
+
+/// Counts how many times it was dropped, and panics while dropping if asked to
+struct DropCounter<'a> {
+    drops: &'a Cell<usize>,
+    panic: bool,
+}
+
+impl Drop for DropCounter<'_> {
+    fn drop(&mut self) {
+        self.drops.set(self.drops.get() + 1);
+
+        if self.panic {
+            panic!("DropCounter panicked");
+        }
+    }
+}
+
+fn counters(drops: &Cell<usize>, panic_at: usize) -> ArrayVec<DropCounter<'_>, 4> {
+    (0..4)
+        .map(|i| DropCounter {
+            drops,
+            panic: i == panic_at,
+        })
+        .collect()
+}
+
+#[test]
+fn edit() {
+    let mut a = ArrayVec::<_, 6>::from_iter(vec![1, 2, 3]);
+
+    assert_eq!(a.insert(0, 0), Ok(()));
+    assert_eq!(a.insert(4, 5), Ok(()));
+    assert_eq!(a.insert(4, 4), Ok(()));
+    assert_eq!(a.as_slice(), &[0, 1, 2, 3, 4, 5]);
+    assert_eq!(a.insert(2, 10), Err(10));
+
+    assert_eq!(a.remove(1), 1);
+    assert_eq!(a.remove(4), 5);
+    assert_eq!(a.as_slice(), &[0, 2, 3, 4]);
+
+    assert_eq!(a.swap_remove(0), 0);
+    assert_eq!(a.as_slice(), &[4, 2, 3]);
+    assert_eq!(a.swap_remove(2), 3);
+    assert_eq!(a.as_slice(), &[4, 2]);
+
+    a.truncate(5);
+    assert_eq!(a.as_slice(), &[4, 2]);
+    a.truncate(1);
+    assert_eq!(a.as_slice(), &[4]);
+
+    let mut a = ArrayVec::<_, 8>::from([1, 2, 3, 4, 5, 6, 7, 8]);
+
+    a.retain(|x| x % 3 != 0);
+    assert_eq!(a.as_slice(), &[1, 2, 4, 5, 7, 8]);
+
+    a.retain_mut(|x| {
+        *x *= 10;
+        *x > 20
+    });
+    assert_eq!(a.as_slice(), &[40, 50, 70, 80]);
+
+    let mut a = ArrayVec::<_, 8>::from([1, 1, 2, 3, 3, 3, 1, 5]);
+
+    a.dedup_by_key(|x| *x / 2);
+    assert_eq!(a.as_slice(), &[1, 2, 1, 5]);
+}
+
+#[test]
+#[should_panic(expected = "insertion index out of bounds!")]
+fn insert_out_of_bounds() {
+    let mut a = ArrayVec::<i32, 4>::default();
+
+    let _ = a.insert(1, 0);
+}
+
+#[test]
+#[should_panic(expected = "removal index out of bounds!")]
+fn remove_out_of_bounds() {
+    let mut a = ArrayVec::<_, 4>::from([0, 1, 2, 3]);
+
+    a.swap_remove(4);
+}
+
+#[test]
+fn edit_drops() {
+    let drops = Cell::new(0);
+
+    let mut arr = counters(&drops, 4);
+    let removed = arr.remove(1);
+
+    assert_eq!(drops.get(), 0);
+    drop(removed);
+    assert_eq!(drops.get(), 1);
+
+    drop(arr.swap_remove(0));
+    assert_eq!(drops.get(), 2);
+
+    arr.truncate(1);
+    assert_eq!(drops.get(), 3);
+    assert_eq!(arr.len(), 1);
+
+    let extra = DropCounter {
+        drops: &drops,
+        panic: false,
+    };
+
+    assert!(arr.insert(0, extra).is_ok());
+    drop(arr);
+    assert_eq!(drops.get(), 5);
+
+    let drops = Cell::new(0);
+    let mut arr = counters(&drops, 4);
+
+    let mut i = 0;
+    arr.retain(|_| {
+        i += 1;
+        i % 2 == 0
+    });
+
+    assert_eq!(drops.get(), 2);
+    assert_eq!(arr.len(), 2);
+
+    drop(arr);
+    assert_eq!(drops.get(), 4);
+
+    let drops = Cell::new(0);
+    let mut arr = counters(&drops, 4);
+
+    arr.dedup_by_key(|_| ());
+
+    assert_eq!(drops.get(), 3);
+    assert_eq!(arr.len(), 1);
+
+    drop(arr);
+    assert_eq!(drops.get(), 4);
+}
+
+#[test]
+fn drain() {
+    let mut a = ArrayVec::<_, 6>::from([0, 1, 2, 3, 4, 5]);
+
+    let mut drain = a.drain(1..5);
+
+    assert_eq!(drain.len(), 4);
+    assert_eq!(drain.next(), Some(1));
+    assert_eq!(drain.next_back(), Some(4));
+    assert_eq!(drain.len(), 2);
+
+    drop(drain);
+    assert_eq!(a.as_slice(), &[0, 5]);
+
+    assert!(a.drain(..).eq(vec![0, 5]));
+    assert!(a.is_empty());
+
+    let mut a = ArrayVec::<_, 6>::from([0, 1, 2, 3, 4, 5]);
+
+    assert!(a.drain(2..=3).rev().eq(vec![3, 2]));
+    assert_eq!(a.as_slice(), &[0, 1, 4, 5]);
+    assert_eq!(a.drain(4..).count(), 0);
+    assert_eq!(a.drain(..1).nth(0), Some(0));
+    assert_eq!(a.drain(1..).nth_back(1), Some(4));
+    assert_eq!(a.as_slice(), &[1]);
+
+    // leaking the drain leaks the tail too, but leaves the `ArrayVec` valid
+    let mut a = ArrayVec::<_, 4>::from([0, 1, 2, 3]);
+
+    std::mem::forget(a.drain(1..2));
+    assert_eq!(a.as_slice(), &[0]);
+}
+
+#[test]
+#[should_panic(expected = "range out of bounds!")]
+fn drain_out_of_bounds() {
+    let mut a = ArrayVec::<_, 4>::from([0, 1, 2, 3]);
+
+    a.drain(2..5);
+}
+
+#[test]
+fn splice() {
+    let mut a = ArrayVec::<_, 6>::default();
+
+    a.extend(vec![0, 1, 2, 3]);
+
+    let removed = a.splice(1..3, vec![10, 20, 30]).ok().unwrap();
+
+    assert!(removed.eq(vec![1, 2]));
+    assert_eq!(a.as_slice(), &[0, 10, 20, 30, 3]);
+
+    assert_eq!(a.splice(..0, vec![-1]).ok().unwrap().len(), 0);
+    assert_eq!(a.as_slice(), &[-1, 0, 10, 20, 30, 3]);
+
+    let rest = a.splice(2..3, vec![7, 8]).err().unwrap().into_inner();
+
+    assert!(rest.eq(vec![7, 8]));
+    assert_eq!(a.as_slice(), &[-1, 0, 10, 20, 30, 3]);
+
+    assert!(a.splice(1.., None).ok().unwrap().eq(vec![0, 10, 20, 30, 3]));
+    assert_eq!(a.as_slice(), &[-1]);
+}
+
+#[test]
+fn try_extend() {
+    let mut a = ArrayVec::<_, 4>::default();
+
+    assert!(a.try_extend(vec![1, 2]).is_ok());
+    assert!(a.try_extend(vec![3, 4]).is_ok());
+    assert!(a.try_extend(None).is_ok());
+    assert_eq!(a.as_slice(), &[1, 2, 3, 4]);
+
+    let mut a = ArrayVec::<_, 4>::from_iter(vec![1, 2]);
+    let err = a.try_extend(3..10).err().unwrap();
+
+    assert_eq!(err.to_string(), "there are too many elements to fit");
+    assert!(err.into_inner().eq(5..10));
+    assert_eq!(a.as_slice(), &[1, 2, 3, 4]);
+
+    assert_eq!(ArrayVec::<_, 3>::try_from_iter(0..3).unwrap().as_slice(), &[0, 1, 2]);
+    assert!(ArrayVec::<_, 3>::try_from_iter(0..5)
+        .err()
+        .unwrap()
+        .into_inner()
+        .eq(3..5));
+
+    let Strict(a) = (0..4).collect::<Strict<ArrayVec<_, 4>>>();
+
+    assert_eq!(a.as_slice(), &[0, 1, 2, 3]);
+}
+
+#[test]
+#[should_panic(expected = "collected more than 4 elements into an ArrayVec")]
+fn strict_overflow() {
+    let _ = (0..5).collect::<Strict<ArrayVec<_, 4>>>();
+}
+
+#[test]
+fn string() {
+    use std::fmt::Write;
+
+    let mut s = ArrayString::<8>::new();
+
+    s.push_str("ab");
+    s.push('é');
+    assert_eq!(s, "abé");
+    assert_eq!(s.len(), 4);
+
+    assert!(s.try_push_str("cdefg").is_err());
+    assert_eq!(s, "abé");
+
+    assert!(write!(s, "{}", 1234).is_ok());
+    assert!(write!(s, "!!").is_err());
+    assert_eq!(s.try_push('💖').err().unwrap().into_inner(), '💖');
+    assert_eq!(s, "abé1234");
+
+    assert_eq!(s.pop(), Some('4'));
+    s.truncate(4);
+    assert_eq!(s.pop(), Some('é'));
+    assert_eq!(s.as_str(), "ab");
+
+    s.as_mut_str().make_ascii_uppercase();
+    assert_eq!(format!("{} {:?}", s, s), "AB \"AB\"");
+
+    s.clear();
+    assert_eq!(s.pop(), None);
+
+    let s: ArrayString<4> = "abcd".parse().unwrap();
+
+    assert!("abcde".parse::<ArrayString<4>>().is_err());
+    assert!(s < "abd".parse::<ArrayString<3>>().unwrap());
+    assert_eq!(s, "abcd".parse::<ArrayString<6>>().unwrap());
+
+    let mut set = std::collections::HashSet::new();
+
+    set.insert(s);
+    assert!(set.contains("abcd"));
+}
+
+#[test]
+#[should_panic(expected = "truncated inside of a char!")]
+fn string_truncate_boundary() {
+    let mut s = ArrayString::<4>::new();
+
+    s.push('é');
+    s.truncate(1);
+}
+
+#[test]
+fn traits() {
+    use std::collections::{BTreeSet, HashSet};
+    use std::io::Write;
+
+    let a = ArrayVec::<_, 4>::from_iter(vec![1, 2, 3]);
+    let b = ArrayVec::<_, 8>::from_iter(vec![1, 2, 3]);
+
+    assert_eq!(a, b);
+    assert_eq!(a, [1, 2, 3]);
+    assert_eq!(a, vec![1, 2, 3]);
+    assert_eq!(a, &[1, 2, 3][..]);
+    assert_ne!(a, [1, 2]);
+    assert_eq!(format!("{:?}", a), "[1, 2, 3]");
+
+    let (shorter, greater) = (ArrayVec::<_, 2>::from([1, 2]), ArrayVec::<_, 2>::from([1, 3]));
+
+    assert!(a < greater);
+    assert!(a > shorter);
+    assert_eq!(a.cmp(&a.clone()), std::cmp::Ordering::Equal);
+
+    let hashes: HashSet<_> = vec![a.clone(), a.clone()].into_iter().collect();
+
+    assert_eq!(hashes.len(), 1);
+    assert!(hashes.contains(&[1, 2, 3][..]));
+
+    let sorted: BTreeSet<_> = vec![b.clone(), ArrayVec::default()].into_iter().collect();
+
+    assert!(sorted.iter().next().unwrap().is_empty());
+
+    let mut a = a;
+
+    for x in &mut a {
+        *x *= 2;
+    }
+
+    assert_eq!((&a).into_iter().sum::<i32>(), 12);
+    assert_eq!(AsRef::<[i32]>::as_ref(&a), &[2, 4, 6]);
+
+    let mut bytes = ArrayVec::<u8, 6>::default();
+
+    assert!(write!(bytes, "abc").is_ok());
+    assert_eq!(bytes.write(b"defgh").unwrap(), 3);
+    assert_eq!(bytes.write(b"i").unwrap(), 0);
+    assert!(bytes.write_all(b"j").is_err());
+    assert_eq!(bytes, *b"abcdef");
+}
+
+#[test]
+fn panic_safety() {
+    let drops = Cell::new(0);
+
+    let mut arr = counters(&drops, 1);
+
+    assert!(catch_unwind(AssertUnwindSafe(|| arr.clear())).is_err());
+    assert_eq!(drops.get(), 4);
+    assert!(arr.is_empty());
+
+    drop(arr);
+
+    assert_eq!(drops.get(), 4);
+
+    let drops = Cell::new(0);
+    let mut iter = counters(&drops, 1).into_iter();
+
+    assert!(catch_unwind(AssertUnwindSafe(|| iter.nth(2))).is_err());
+    assert_eq!(drops.get(), 2);
+    assert_eq!(iter.len(), 2);
+
+    drop(iter);
+
+    assert_eq!(drops.get(), 4);
+
+    let drops = Cell::new(0);
+    let mut iter = counters(&drops, 2).into_iter();
+
+    assert!(catch_unwind(AssertUnwindSafe(|| iter.nth_back(5))).is_err());
+    assert_eq!(drops.get(), 4);
+    assert_eq!(iter.len(), 0);
+
+    drop(iter);
+
+    assert_eq!(drops.get(), 4);
+}
+
+#[test]
+fn edit_panic_safety() {
+    let drops = Cell::new(0);
+    let mut arr = counters(&drops, 2);
+
+    assert!(catch_unwind(AssertUnwindSafe(|| arr.truncate(1))).is_err());
+    assert_eq!(drops.get(), 3);
+    assert_eq!(arr.len(), 1);
+
+    drop(arr);
+    assert_eq!(drops.get(), 4);
+
+    // the predicate panics on the third element, after the first was removed
+    let drops = Cell::new(0);
+    let mut arr = counters(&drops, 4);
+    let mut i = 0;
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        arr.retain(|_| {
+            i += 1;
+            assert!(i != 3, "predicate panicked");
+            i != 1
+        })
+    }));
+
+    assert!(result.is_err());
+    assert_eq!(drops.get(), 1);
+    assert_eq!(arr.len(), 3);
+
+    drop(arr);
+    assert_eq!(drops.get(), 4);
+
+    // dropping the second element panics, after it was already removed
+    let drops = Cell::new(0);
+    let mut arr = counters(&drops, 1);
+
+    assert!(catch_unwind(AssertUnwindSafe(|| arr.retain(|_| false))).is_err());
+    assert_eq!(drops.get(), 2);
+    assert_eq!(arr.len(), 2);
+
+    drop(arr);
+    assert_eq!(drops.get(), 4);
+}
+
+#[test]
+fn drain_panic_safety() {
+    // dropping the rest of the drained elements panics, the tail is still moved back
+    let drops = Cell::new(0);
+    let mut arr = counters(&drops, 1);
+    let mut drain = arr.drain(..3);
+
+    assert!(drain.next().is_some());
+    assert!(catch_unwind(AssertUnwindSafe(|| drop(drain))).is_err());
+    assert_eq!(drops.get(), 3);
+    assert_eq!(arr.len(), 1);
+
+    drop(arr);
+    assert_eq!(drops.get(), 4);
+
+    // the replacement panics part way through, the written elements and the tail are kept
+    let drops = Cell::new(0);
+    let mut arr = counters(&drops, 4);
+    let mut i = 0;
+    let replace_with = (0..2).map(|_| {
+        i += 1;
+        assert!(i != 2, "replacement panicked");
+        DropCounter {
+            drops: &drops,
+            panic: false,
+        }
+    });
+
+    let result = catch_unwind(AssertUnwindSafe(|| arr.splice(1..3, replace_with).is_ok()));
+
+    assert!(result.is_err());
+    assert_eq!(drops.get(), 2);
+    assert_eq!(arr.len(), 3);
+
+    drop(arr);
+    assert_eq!(drops.get(), 5);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() {
+    let mut a = ArrayVec::<i32, 4>::default();
+
+    a.extend(vec![1, 2, 3]);
+
+    assert_eq!(serde_json::to_string(&a).unwrap(), "[1,2,3]");
+
+    let b: ArrayVec<i32, 4> = serde_json::from_str("[1,2,3]").unwrap();
+
+    assert_eq!(a.as_slice(), b.as_slice());
+
+    let b: ArrayVec<i32, 4> = serde_json::from_str("[]").unwrap();
+
+    assert!(b.is_empty());
+
+    let err = serde_json::from_str::<ArrayVec<i32, 2>>("[1,2,3]").err().unwrap();
+
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 3, expected a sequence of at most 2 elements"));
+
+    let err = serde_json::from_str::<ArrayVec<i32, 2>>("[1,2,3,4,5,6,7]").err().unwrap();
+
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 7, expected a sequence of at most 2 elements"));
+}
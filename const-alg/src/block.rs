@@ -0,0 +1,135 @@
+use crate::{collect_array, collect_mat, into_iter, Matrix};
+
+use array_vec::ArrayVec;
+
+// The sizes of the blocks can't be written in terms of each other yet (i.e. `Matrix<T, N, { A + B }>`),
+// so all of the sizes are separate parameters and the constraints between them are checked when called.
+// The sizes are usually inferred from the inputs and the output
+
+impl<T, const N: usize, const M: usize> Matrix<T, { N }, { M }> {
+    /// Places `a` and `b` side by side, `[a b]`
+    ///
+    /// Panics if `M != A + B`
+    pub fn hstack<const A: usize, const B: usize>(
+        a: Matrix<T, { N }, { A }>,
+        b: Matrix<T, { N }, { B }>,
+    ) -> Self {
+        assert_eq!(
+            A + B,
+            M,
+            "the blocks don't have the correct number of columns"
+        );
+
+        let rows = into_iter(a.0).zip(into_iter(b.0)).map(|(a, b)| unsafe {
+            let arr: [_; M] = collect_array(into_iter(a).chain(into_iter(b)));
+
+            arr
+        });
+
+        unsafe { collect_mat(rows) }
+    }
+
+    /// Places `a` on top of `b`, `[a; b]`
+    ///
+    /// Panics if `N != A + B`
+    pub fn vstack<const A: usize, const B: usize>(
+        a: Matrix<T, { A }, { M }>,
+        b: Matrix<T, { B }, { M }>,
+    ) -> Self {
+        assert_eq!(A + B, N, "the blocks don't have the correct number of rows");
+
+        unsafe { collect_mat(into_iter(a.0).chain(into_iter(b.0))) }
+    }
+
+    /// Builds the block matrix `[a b; c d]`
+    ///
+    /// Panics if `N != R + P` or `M != C + Q`
+    pub fn from_blocks<const R: usize, const C: usize, const P: usize, const Q: usize>(
+        a: Matrix<T, { R }, { C }>,
+        b: Matrix<T, { R }, { Q }>,
+        c: Matrix<T, { P }, { C }>,
+        d: Matrix<T, { P }, { Q }>,
+    ) -> Self {
+        assert_eq!(R + P, N, "the blocks don't have the correct number of rows");
+        assert_eq!(
+            C + Q,
+            M,
+            "the blocks don't have the correct number of columns"
+        );
+
+        let top = into_iter(a.0).zip(into_iter(b.0));
+        let bottom = into_iter(c.0).zip(into_iter(d.0));
+
+        let rows = top
+            .map(|(a, b)| (into_iter(a), into_iter(b)))
+            .chain(bottom.map(|(c, d)| (into_iter(c), into_iter(d))));
+
+        let rows = rows.map(|(left, right)| unsafe {
+            let arr: [_; M] = collect_array(left.chain(right));
+
+            arr
+        });
+
+        unsafe { collect_mat(rows) }
+    }
+
+    /// Splits the matrix into the first `K` columns and the remaining `L` columns
+    ///
+    /// Panics if `M != K + L`
+    pub fn split_cols<const K: usize, const L: usize>(
+        self,
+    ) -> (Matrix<T, { N }, { K }>, Matrix<T, { N }, { L }>) {
+        assert_eq!(
+            K + L,
+            M,
+            "the blocks don't have the correct number of columns"
+        );
+
+        let rows = into_iter(self.0).map(|row| unsafe {
+            let mut row = into_iter(row);
+            let left: [_; K] = collect_array(row.by_ref());
+            let right: [_; L] = collect_array(row);
+
+            (left, right)
+        });
+
+        let (left, right): (ArrayVec<_, { N }>, ArrayVec<_, { N }>) = rows.unzip();
+
+        unsafe { (collect_mat(left), collect_mat(right)) }
+    }
+
+    /// Splits the matrix into the first `K` rows and the remaining `L` rows
+    ///
+    /// Panics if `N != K + L`
+    pub fn split_rows<const K: usize, const L: usize>(
+        self,
+    ) -> (Matrix<T, { K }, { M }>, Matrix<T, { L }, { M }>) {
+        assert_eq!(K + L, N, "the blocks don't have the correct number of rows");
+
+        let mut rows = into_iter(self.0);
+        let top = unsafe { collect_mat(rows.by_ref()) };
+        let bottom = unsafe { collect_mat(rows) };
+
+        (top, bottom)
+    }
+
+    /// Splits the matrix into the four blocks `[a b; c d]`,
+    /// where `a` is `R x C`, the inverse of `from_blocks`
+    ///
+    /// Panics if `N != R + P` or `M != C + Q`
+    #[allow(clippy::type_complexity)]
+    pub fn split_blocks<const R: usize, const C: usize, const P: usize, const Q: usize>(
+        self,
+    ) -> (
+        Matrix<T, { R }, { C }>,
+        Matrix<T, { R }, { Q }>,
+        Matrix<T, { P }, { C }>,
+        Matrix<T, { P }, { Q }>,
+    ) {
+        let (top, bottom) = self.split_rows::<{ R }, { P }>();
+        let (a, b) = top.split_cols::<{ C }, { Q }>();
+        let (c, d) = bottom.split_cols::<{ C }, { Q }>();
+
+        (a, b, c, d)
+    }
+}
@@ -0,0 +1,162 @@
+use crate::{ColVector, Matrix, SquareMatrix};
+use num::{Float, One, Zero};
+
+/// The Cholesky decomposition of a symmetric positive-definite matrix
+///
+/// This represents `A = L * L^T`, where `L` is a lower triangular matrix with a positive diagonal
+#[derive(Debug, Clone, Copy)]
+pub struct Cholesky<T, const N: usize> {
+    l: SquareMatrix<T, { N }>,
+}
+
+impl<T: Float, const N: usize> Matrix<T, { N }, { N }> {
+    /// Calculates the Cholesky decomposition of the matrix
+    ///
+    /// Only the lower triangle of the matrix is read, the upper triangle is assumed to mirror it.
+    /// If the matrix is not positive-definite, `None` is returned
+    pub fn cholesky(&self) -> Option<Cholesky<T, { N }>> {
+        Cholesky::new(*self)
+    }
+}
+
+impl<T: Float, const N: usize> Cholesky<T, { N }> {
+    /// Decomposes the given matrix
+    ///
+    /// Only the lower triangle of the matrix is read, the upper triangle is assumed to mirror it.
+    /// If the matrix is not positive-definite, `None` is returned
+    pub fn new(mat: SquareMatrix<T, { N }>) -> Option<Self> {
+        let mut l = SquareMatrix::<T, { N }>::zero();
+
+        for j in 0..N {
+            let sum = (0..j).fold(T::zero(), |acc, k| acc + l[(j, k)] * l[(j, k)]);
+            let diag = mat[(j, j)] - sum;
+
+            if diag <= T::zero() || diag.is_nan() {
+                return None;
+            }
+
+            let diag = diag.sqrt();
+
+            l[(j, j)] = diag;
+
+            for i in j + 1..N {
+                let sum = (0..j).fold(T::zero(), |acc, k| acc + l[(i, k)] * l[(j, k)]);
+
+                l[(i, j)] = (mat[(i, j)] - sum) / diag;
+            }
+        }
+
+        Some(Self { l })
+    }
+
+    /// The lower triangular factor
+    pub fn l(&self) -> SquareMatrix<T, { N }> {
+        self.l
+    }
+
+    /// The determinant of the decomposed matrix
+    pub fn det(&self) -> T {
+        let det = (0..N).fold(T::one(), |acc, i| acc * self.l[(i, i)]);
+
+        det * det
+    }
+
+    /// Solves `A * X = B` for `X`, where `A` is the decomposed matrix
+    ///
+    /// Each column of `b` is a separate right hand side
+    pub fn solve<const K: usize>(&self, b: Matrix<T, { N }, { K }>) -> Matrix<T, { N }, { K }> {
+        let l = &self.l;
+        let mut x = b;
+
+        // forward substitution with `L`
+        for i in 0..N {
+            for j in 0..i {
+                let (factor, x_j) = (l[(i, j)], x.0[j]);
+
+                x.0[i]
+                    .iter_mut()
+                    .zip(x_j.iter())
+                    .for_each(|(x, &y)| *x = *x - factor * y);
+            }
+
+            let pivot = l[(i, i)];
+
+            x.0[i].iter_mut().for_each(|x| *x = *x / pivot);
+        }
+
+        // backward substitution with `L^T`
+        for i in (0..N).rev() {
+            for j in i + 1..N {
+                let (factor, x_j) = (l[(j, i)], x.0[j]);
+
+                x.0[i]
+                    .iter_mut()
+                    .zip(x_j.iter())
+                    .for_each(|(x, &y)| *x = *x - factor * y);
+            }
+
+            let pivot = l[(i, i)];
+
+            x.0[i].iter_mut().for_each(|x| *x = *x / pivot);
+        }
+
+        x
+    }
+
+    /// The inverse of the decomposed matrix
+    pub fn inverse(&self) -> SquareMatrix<T, { N }> {
+        self.solve(SquareMatrix::one())
+    }
+
+    /// Updates the decomposition to represent `A + x * x^T`
+    pub fn rank_one_update(&mut self, x: ColVector<T, { N }>) {
+        let l = &mut self.l;
+        let mut x = x.0;
+
+        for k in 0..N {
+            let l_kk = l[(k, k)];
+            let r = l_kk.hypot(x[k][0]);
+            let c = r / l_kk;
+            let s = x[k][0] / l_kk;
+
+            l[(k, k)] = r;
+
+            for i in k + 1..N {
+                l[(i, k)] = (l[(i, k)] + s * x[i][0]) / c;
+                x[i][0] = c * x[i][0] - s * l[(i, k)];
+            }
+        }
+    }
+
+    /// Updates the decomposition to represent `A - x * x^T`
+    ///
+    /// If `A - x * x^T` is not positive-definite, this returns false and leaves the decomposition unchanged
+    pub fn rank_one_downdate(&mut self, x: ColVector<T, { N }>) -> bool {
+        let mut l = self.l;
+        let mut x = x.0;
+
+        for k in 0..N {
+            let l_kk = l[(k, k)];
+            let r_sq = (l_kk - x[k][0]) * (l_kk + x[k][0]);
+
+            if r_sq <= T::zero() || r_sq.is_nan() {
+                return false;
+            }
+
+            let r = r_sq.sqrt();
+            let c = r / l_kk;
+            let s = x[k][0] / l_kk;
+
+            l[(k, k)] = r;
+
+            for i in k + 1..N {
+                l[(i, k)] = (l[(i, k)] - s * x[i][0]) / c;
+                x[i][0] = c * x[i][0] - s * l[(i, k)];
+            }
+        }
+
+        self.l = l;
+
+        true
+    }
+}
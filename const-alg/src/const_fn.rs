@@ -0,0 +1,125 @@
+use crate::Matrix;
+
+// Trait methods can't be called in a `const fn` yet, so the arithmetic below is written out for
+// each primitive type, and the constructors only use the associated constants of `ConstNum`
+
+/// A number whose zero and one are known at compile time
+///
+/// This lets matricies of it be built in `const` contexts, like `static` lookup tables
+pub trait ConstNum: Copy {
+    /// The additive identity
+    const ZERO: Self;
+    /// The multiplicative identity
+    const ONE: Self;
+}
+
+impl<T: ConstNum, const N: usize, const M: usize> Matrix<T, { N }, { M }> {
+    /// A matrix of zeros, usable in `const` contexts
+    ///
+    /// ```rust
+    /// # use const_alg::Matrix;
+    /// static ZEROS: Matrix<f32, 2, 3> = Matrix::zeros();
+    ///
+    /// assert_eq!(ZEROS, Matrix([[0.0; 3]; 2]));
+    /// ```
+    pub const fn zeros() -> Self {
+        Matrix([[T::ZERO; M]; N])
+    }
+
+    /// The transpose of the matrix, usable in `const` contexts
+    pub const fn const_transpose(&self) -> Matrix<T, { M }, { N }> {
+        let mut out = Matrix::<T, { M }, { N }>::zeros();
+        let mut i = 0;
+
+        while i < N {
+            let mut j = 0;
+
+            while j < M {
+                out.0[j][i] = self.0[i][j];
+                j += 1;
+            }
+
+            i += 1;
+        }
+
+        out
+    }
+}
+
+impl<T: ConstNum, const N: usize> Matrix<T, { N }, { N }> {
+    /// The identity matrix, usable in `const` contexts
+    ///
+    /// ```rust
+    /// # use const_alg::Matrix;
+    /// const I: Matrix<i32, 2, 2> = Matrix::identity();
+    ///
+    /// assert_eq!(I, Matrix([[1, 0], [0, 1]]));
+    /// ```
+    pub const fn identity() -> Self {
+        let mut out = Self::zeros();
+        let mut i = 0;
+
+        while i < N {
+            out.0[i][i] = T::ONE;
+            i += 1;
+        }
+
+        out
+    }
+}
+
+macro_rules! const_num {
+    ($($t:ty => $zero:literal, $one:literal;)*) => {$(
+        impl ConstNum for $t {
+            const ZERO: Self = $zero;
+            const ONE: Self = $one;
+        }
+
+        impl<const N: usize, const M: usize> Matrix<$t, { N }, { M }> {
+            /// The matrix product of `self` and `other`, usable in `const` contexts
+            pub const fn const_mul<const K: usize>(
+                &self,
+                other: &Matrix<$t, { M }, { K }>,
+            ) -> Matrix<$t, { N }, { K }> {
+                let mut out = Matrix::<$t, { N }, { K }>::zeros();
+                let mut i = 0;
+
+                while i < N {
+                    let mut j = 0;
+
+                    while j < K {
+                        let mut k = 0;
+
+                        while k < M {
+                            out.0[i][j] += self.0[i][k] * other.0[k][j];
+                            k += 1;
+                        }
+
+                        j += 1;
+                    }
+
+                    i += 1;
+                }
+
+                out
+            }
+        }
+    )*};
+}
+
+const_num! {
+    i8 => 0, 1;
+    i16 => 0, 1;
+    i32 => 0, 1;
+    i64 => 0, 1;
+    i128 => 0, 1;
+    isize => 0, 1;
+    u8 => 0, 1;
+    u16 => 0, 1;
+    u32 => 0, 1;
+    u64 => 0, 1;
+    u128 => 0, 1;
+    usize => 0, 1;
+    f32 => 0.0, 1.0;
+    f64 => 0.0, 1.0;
+}
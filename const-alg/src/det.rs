@@ -0,0 +1,83 @@
+use crate::{Lu, Matrix, SquareMatrix};
+use num::{PrimInt, Signed};
+
+/// A scalar that knows how to compute the determinant of a square matrix of itself
+///
+/// Only the signed integer types (`i8` through `isize`) use Bareiss' fraction-free elimination,
+/// because it needs to negate, so the result is exact as long as the intermediate values don't overflow.
+/// Unsigned integers don't implement this.
+///
+/// `f32` and `f64` use the LU decomposition with partial pivoting, see `Lu`
+pub trait Determinant: Sized {
+    /// Calculates the determinant of `mat`, `Matrix::det` is usually nicer to call
+    ///
+    /// The determinant of a `0x0` matrix is one
+    fn determinant<const N: usize>(mat: SquareMatrix<Self, { N }>) -> Self;
+}
+
+macro_rules! impl_bareiss {
+    ($($type:ty),*) => {$(
+        impl Determinant for $type {
+            fn determinant<const N: usize>(mat: SquareMatrix<Self, { N }>) -> Self {
+                bareiss(mat)
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_lu {
+    ($($type:ty),*) => {$(
+        impl Determinant for $type {
+            fn determinant<const N: usize>(mat: SquareMatrix<Self, { N }>) -> Self {
+                Lu::new(mat).det()
+            }
+        }
+    )*};
+}
+
+impl_bareiss!(i8, i16, i32, i64, i128, isize);
+impl_lu!(f32, f64);
+
+/// Bareiss' algorithm, every division here is exact
+fn bareiss<T: PrimInt + Signed, const N: usize>(mut mat: SquareMatrix<T, { N }>) -> T {
+    if N == 0 {
+        return T::one();
+    }
+
+    let mut sign = T::one();
+    let mut prev = T::one();
+
+    for k in 0..N - 1 {
+        if mat[(k, k)].is_zero() {
+            match (k + 1..N).find(|&i| !mat[(i, k)].is_zero()) {
+                Some(i) => {
+                    mat.swap(k, i);
+                    sign = -sign;
+                }
+                None => return T::zero(),
+            }
+        }
+
+        let pivot = mat[(k, k)];
+
+        for i in k + 1..N {
+            for j in k + 1..N {
+                mat[(i, j)] = (mat[(i, j)] * pivot - mat[(i, k)] * mat[(k, j)]) / prev;
+            }
+        }
+
+        prev = pivot;
+    }
+
+    sign * mat[(N - 1, N - 1)]
+}
+
+impl<T: Determinant + Clone, const N: usize> Matrix<T, { N }, { N }> {
+    /// Calculates the determinant of the matrix
+    ///
+    /// For integers this is exact (as long as nothing overflows),
+    /// for floats this is subject to the usual rounding errors
+    pub fn det(&self) -> T {
+        T::determinant(self.clone())
+    }
+}
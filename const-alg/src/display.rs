@@ -0,0 +1,273 @@
+use crate::Matrix;
+
+use array_vec::ArrayVec;
+
+use std::fmt::{self, Write};
+
+/// Options for `Matrix::display_with`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DisplayOptions {
+    /// The maximum number of rows to show, the rest are replaced with a row of `⋮`
+    pub max_rows: usize,
+    /// The maximum number of columns to show, the rest are replaced with a column of `…`
+    pub max_cols: usize,
+}
+
+impl DisplayOptions {
+    /// Shows every element, this is what the `Display` impl of `Matrix` uses
+    pub const FULL: Self = Self {
+        max_rows: usize::MAX,
+        max_cols: usize::MAX,
+    };
+}
+
+/// `8` rows and `8` columns
+impl Default for DisplayOptions {
+    fn default() -> Self {
+        Self {
+            max_rows: 8,
+            max_cols: 8,
+        }
+    }
+}
+
+/// Displays a matrix with the given options
+///
+/// You can make one with `Matrix::display_with`
+pub struct MatrixDisplay<'a, T, const N: usize, const M: usize> {
+    mat: &'a Matrix<T, { N }, { M }>,
+    options: DisplayOptions,
+}
+
+impl<T, const N: usize, const M: usize> Matrix<T, { N }, { M }> {
+    /// Displays the matrix, leaving out the middle rows and columns if there are too many
+    ///
+    /// ```rust
+    /// # use const_alg::{DisplayOptions, Matrix};
+    /// let m = Matrix([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// let options = DisplayOptions { max_rows: 2, max_cols: 2 };
+    ///
+    /// assert_eq!(m.display_with(options).to_string(), "⎡ 1 … 3 ⎤\n⎢ ⋮ ⋱ ⋮ ⎥\n⎣ 7 … 9 ⎦");
+    /// ```
+    pub fn display_with(&self, options: DisplayOptions) -> MatrixDisplay<'_, T, { N }, { M }> {
+        MatrixDisplay { mat: self, options }
+    }
+}
+
+/// The rows (or columns) that are shown out of `len`, `None` is where the ellipsis goes
+fn shown(len: usize, max: usize) -> impl Iterator<Item = Option<usize>> + Clone {
+    let (head, tail) = if len <= max {
+        (len, 0)
+    } else {
+        (max - max / 2, max / 2)
+    };
+
+    (0..head)
+        .map(Some)
+        .chain((len > max).then_some(None))
+        .chain((len - tail..len).map(Some))
+}
+
+/// Counts the characters written to it, so cells can be measured without formatting them to a `String`
+struct Counter(usize);
+
+impl Write for Counter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+/// The flags of the formatter that are passed on to each element
+#[derive(Clone, Copy)]
+struct Spec {
+    precision: Option<usize>,
+    /// The `+` flag
+    plus: bool,
+    /// The width to pad to with zeros, if the `0` flag is set
+    zero_pad: Option<usize>,
+}
+
+/// Writes `$x` into `$w` with the flags of `$spec`, `$ty` is the type of the formatting trait, like `e`
+///
+/// Formatting flags can't be set at runtime, so this picks the format string that has them
+macro_rules! write_elem {
+    ($w:expr, $x:expr, $spec:expr $(, $ty:literal)?) => {{
+        let Spec { precision, plus, zero_pad } = $spec;
+        let (width, prec) = (zero_pad.unwrap_or(0), precision.unwrap_or(0));
+
+        match (plus, zero_pad.is_some(), precision.is_some()) {
+            (false, false, false) => write!($w, concat!("{:", $($ty,)? "}"), $x),
+            (false, false, true) => write!($w, concat!("{:.prec$", $($ty,)? "}"), $x, prec = prec),
+            (false, true, false) => write!($w, concat!("{:0width$", $($ty,)? "}"), $x, width = width),
+            (false, true, true) => write!(
+                $w,
+                concat!("{:0width$.prec$", $($ty,)? "}"),
+                $x,
+                width = width,
+                prec = prec
+            ),
+            (true, false, false) => write!($w, concat!("{:+", $($ty,)? "}"), $x),
+            (true, false, true) => write!($w, concat!("{:+.prec$", $($ty,)? "}"), $x, prec = prec),
+            (true, true, false) => write!($w, concat!("{:+0width$", $($ty,)? "}"), $x, width = width),
+            (true, true, true) => write!(
+                $w,
+                concat!("{:+0width$.prec$", $($ty,)? "}"),
+                $x,
+                width = width,
+                prec = prec
+            ),
+        }
+    }};
+}
+
+impl<T, const N: usize, const M: usize> MatrixDisplay<'_, T, { N }, { M }> {
+    /// Writes the cell at `(row, col)` out of the shown rows and columns, `None` is an ellipsis
+    fn cell<E>(
+        &self,
+        w: &mut dyn Write,
+        elem: &E,
+        row: Option<usize>,
+        col: Option<usize>,
+        spec: Spec,
+    ) -> fmt::Result
+    where
+        E: Fn(&mut dyn Write, &T, Spec) -> fmt::Result,
+    {
+        match (row, col) {
+            (Some(row), Some(col)) => elem(w, &self.mat.0[row][col], spec),
+            (Some(_), None) => w.write_char('…'),
+            (None, Some(_)) => w.write_char('⋮'),
+            (None, None) => w.write_char('⋱'),
+        }
+    }
+
+    /// Measures every shown element written with `elem`, then writes them lined up in columns
+    ///
+    /// The width of the formatter is the minimum width of each column,
+    /// and with the `0` flag the elements are padded with zeros to the width of their column
+    fn write<E>(&self, f: &mut fmt::Formatter<'_>, elem: E) -> fmt::Result
+    where
+        E: Fn(&mut dyn Write, &T, Spec) -> fmt::Result,
+    {
+        if N == 0 {
+            return f.write_str("[ ]");
+        }
+
+        let rows = shown(N, self.options.max_rows);
+        let cols = shown(M, self.options.max_cols);
+        let fill = f.fill();
+        let align = f.align();
+        let spec = Spec {
+            precision: f.precision(),
+            plus: f.sign_plus(),
+            zero_pad: None,
+        };
+
+        let measure = |row, col, spec| {
+            let mut counter = Counter(0);
+            self.cell(&mut counter, &elem, row, col, spec)
+                .map(|()| counter.0)
+        };
+
+        // there are never more shown columns than `M`, the ellipsis takes the place of a hidden one
+        let mut widths = ArrayVec::<usize, { M }>::default();
+
+        for col in cols.clone() {
+            let mut width = f.width().unwrap_or(0);
+
+            for row in rows.clone() {
+                width = width.max(measure(row, col, spec)?);
+            }
+
+            let _ = widths.push(width);
+        }
+
+        let len = rows.clone().count();
+
+        for (i, row) in rows.enumerate() {
+            let (open, close) = match i {
+                _ if len == 1 => ('[', ']'),
+                0 => ('⎡', '⎤'),
+                _ if i == len - 1 => ('⎣', '⎦'),
+                _ => ('⎢', '⎥'),
+            };
+
+            if i != 0 {
+                f.write_char('\n')?;
+            }
+
+            f.write_char(open)?;
+
+            for (col, &width) in cols.clone().zip(widths.iter()) {
+                // zero padded elements fill their column by themselves
+                let spec = Spec {
+                    zero_pad: f.sign_aware_zero_pad().then_some(width),
+                    ..spec
+                };
+                let padding = width - measure(row, col, spec)?;
+
+                let (before, after) = match align {
+                    Some(fmt::Alignment::Left) => (0, padding),
+                    Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+                    _ => (padding, 0),
+                };
+
+                f.write_char(' ')?;
+
+                for _ in 0..before {
+                    f.write_char(fill)?;
+                }
+
+                self.cell(f, &elem, row, col, spec)?;
+
+                for _ in 0..after {
+                    f.write_char(fill)?;
+                }
+            }
+
+            f.write_char(' ')?;
+            f.write_char(close)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: fmt::Display, const N: usize, const M: usize> fmt::Display
+    for MatrixDisplay<'_, T, { N }, { M }>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write(f, |w, x, spec| write_elem!(w, x, spec))
+    }
+}
+
+impl<T: fmt::LowerExp, const N: usize, const M: usize> fmt::LowerExp
+    for MatrixDisplay<'_, T, { N }, { M }>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write(f, |w, x, spec| write_elem!(w, x, spec, "e"))
+    }
+}
+
+/// Prints the matrix with its columns lined up, the width, precision, fill, alignment,
+/// and the `+` and `0` flags apply to each element
+///
+/// ```rust
+/// # use const_alg::Matrix;
+/// let m = Matrix([[1.0, -2.5], [10.0, 0.3]]);
+///
+/// assert_eq!(format!("{:.1}", m), "⎡  1.0 -2.5 ⎤\n⎣ 10.0  0.3 ⎦");
+/// ```
+impl<T: fmt::Display, const N: usize, const M: usize> fmt::Display for Matrix<T, { N }, { M }> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.display_with(DisplayOptions::FULL), f)
+    }
+}
+
+/// Like `Display`, but each element is printed in scientific notation
+impl<T: fmt::LowerExp, const N: usize, const M: usize> fmt::LowerExp for Matrix<T, { N }, { M }> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerExp::fmt(&self.display_with(DisplayOptions::FULL), f)
+    }
+}
@@ -0,0 +1,534 @@
+// the reductions below mirror the textbook algorithms, which are clearer with explicit indices
+#![allow(clippy::needless_range_loop)]
+
+use crate::{Matrix, SquareMatrix};
+use num::{Complex, Float, One};
+
+use std::cmp::Ordering;
+use std::fmt;
+
+/// An iterative algorithm didn't converge within its iteration limit
+///
+/// This is returned by the eigenvalue algorithms and the singular value decomposition
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoConvergence;
+
+impl fmt::Display for NoConvergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("the iterative algorithm did not converge")
+    }
+}
+
+impl std::error::Error for NoConvergence {}
+
+/// The order to sort eigenvalues in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    /// Smallest eigenvalue first
+    Ascending,
+    /// Largest eigenvalue first
+    Descending,
+    /// Whatever order the algorithm produced them in
+    Unsorted,
+}
+
+/// Options for `Matrix::symmetric_eigen_with`
+#[derive(Debug, Clone, Copy)]
+pub struct JacobiOptions<T> {
+    /// The maximum number of sweeps over the off-diagonal elements before giving up
+    pub max_sweeps: usize,
+    /// The algorithm stops once the norm of the off-diagonal elements is not greater than
+    /// `tolerance` times the norm of the whole matrix
+    pub tolerance: T,
+    /// The order of the eigenvalues, and their corrosponding eigenvectors
+    pub sort: SortOrder,
+}
+
+/// `50` sweeps, a tolerance of `T::epsilon()`, and ascending eigenvalues
+impl<T: Float> Default for JacobiOptions<T> {
+    fn default() -> Self {
+        Self {
+            max_sweeps: 50,
+            tolerance: T::epsilon(),
+            sort: SortOrder::Ascending,
+        }
+    }
+}
+
+/// The eigendecomposition of a symmetric matrix
+///
+/// This represents `A = V * D * V^T`, where `D` is a diagonal matrix of the eigenvalues,
+/// and `V` is an orthogonal matrix with the eigenvectors as its columns
+#[derive(Debug, Clone, Copy)]
+pub struct SymmetricEigen<T, const N: usize> {
+    values: [T; N],
+    vectors: SquareMatrix<T, { N }>,
+}
+
+impl<T: Float, const N: usize> SymmetricEigen<T, { N }> {
+    /// The eigenvalues
+    pub fn eigenvalues(&self) -> [T; N] {
+        self.values
+    }
+
+    /// The eigenvectors, column `i` corrosponds to `eigenvalues()[i]`
+    pub fn eigenvectors(&self) -> SquareMatrix<T, { N }> {
+        self.vectors
+    }
+}
+
+impl<T: Float, const N: usize> Matrix<T, { N }, { N }> {
+    /// Calculates the eigenvalues and eigenvectors of a symmetric matrix with the default `JacobiOptions`
+    ///
+    /// The matrix is assumed to be symmetric
+    pub fn symmetric_eigen(&self) -> Result<SymmetricEigen<T, { N }>, NoConvergence> {
+        self.symmetric_eigen_with(JacobiOptions::default())
+    }
+
+    /// Calculates the eigenvalues and eigenvectors of a symmetric matrix with cyclic Jacobi rotations
+    ///
+    /// The matrix is assumed to be symmetric
+    pub fn symmetric_eigen_with(
+        &self,
+        options: JacobiOptions<T>,
+    ) -> Result<SymmetricEigen<T, { N }>, NoConvergence> {
+        let mut a = *self;
+        let mut v = SquareMatrix::<T, { N }>::one();
+
+        // both norms are measured in units of the largest element, so squaring can't overflow or underflow
+        let scale = a
+            .iter()
+            .flat_map(|row| row.iter())
+            .fold(T::zero(), |acc, x| acc.max(x.abs()));
+        let scale = if scale.is_zero() { T::one() } else { scale };
+
+        let norm = a
+            .iter()
+            .flat_map(|row| row.iter())
+            .fold(T::zero(), |acc, &x| acc + (x / scale) * (x / scale))
+            .sqrt();
+        let two = T::one() + T::one();
+
+        for sweep in 0..=options.max_sweeps {
+            if off_diagonal(&a, scale) <= options.tolerance * norm {
+                return Ok(sorted(a, v, options.sort));
+            }
+
+            if sweep == options.max_sweeps {
+                break;
+            }
+
+            for p in 0..N {
+                for q in p + 1..N {
+                    if a[(p, q)].is_zero() {
+                        continue;
+                    }
+
+                    let theta = (a[(q, q)] - a[(p, p)]) / (two * a[(p, q)]);
+                    let t = theta.signum() / (theta.abs() + theta.hypot(T::one()));
+                    let c = T::one() / t.hypot(T::one());
+                    let s = t * c;
+
+                    for row in a.iter_mut().chain(v.iter_mut()) {
+                        let (x, y) = (row[p], row[q]);
+
+                        row[p] = c * x - s * y;
+                        row[q] = s * x + c * y;
+                    }
+
+                    let (row_p, row_q) = (a.0[p], a.0[q]);
+
+                    for (k, (&x, &y)) in row_p.iter().zip(row_q.iter()).enumerate() {
+                        a[(p, k)] = c * x - s * y;
+                        a[(q, k)] = s * x + c * y;
+                    }
+
+                    a[(p, q)] = T::zero();
+                    a[(q, p)] = T::zero();
+                }
+            }
+        }
+
+        Err(NoConvergence)
+    }
+}
+
+/// The norm of the off-diagonal elements, divided by `scale`
+fn off_diagonal<T: Float, const N: usize>(a: &SquareMatrix<T, { N }>, scale: T) -> T {
+    let mut sum = T::zero();
+
+    for (i, row) in a.iter().enumerate() {
+        for (j, &x) in row.iter().enumerate() {
+            if i != j {
+                sum = sum + (x / scale) * (x / scale);
+            }
+        }
+    }
+
+    sum.sqrt()
+}
+
+fn sorted<T: Float, const N: usize>(
+    a: SquareMatrix<T, { N }>,
+    v: SquareMatrix<T, { N }>,
+    sort: SortOrder,
+) -> SymmetricEigen<T, { N }> {
+    let mut order = [0; N];
+
+    for (i, o) in order.iter_mut().enumerate() {
+        *o = i;
+    }
+
+    let cmp = |&i: &usize, &j: &usize| a[(i, i)].partial_cmp(&a[(j, j)]).unwrap_or(Ordering::Equal);
+
+    match sort {
+        SortOrder::Ascending => order.sort_by(cmp),
+        SortOrder::Descending => order.sort_by(|i, j| cmp(j, i)),
+        SortOrder::Unsorted => (),
+    }
+
+    let mut values = [T::zero(); N];
+    let mut vectors = v;
+
+    for (i, &o) in order.iter().enumerate() {
+        values[i] = a[(o, o)];
+
+        for (row, v_row) in vectors.iter_mut().zip(v.iter()) {
+            row[i] = v_row[o];
+        }
+    }
+
+    SymmetricEigen { values, vectors }
+}
+
+impl<T: Float, const N: usize> Matrix<T, { N }, { N }> {
+    /// Calculates the eigenvalues of a general square matrix
+    ///
+    /// The matrix is balanced, reduced to upper Hessenberg form, and then the eigenvalues
+    /// are found with the Francis double shift QR algorithm. Complex eigenvalues come in conjugate pairs,
+    /// and the eigenvalues are not in any particular order
+    pub fn eigenvalues(&self) -> Result<[Complex<T>; N], NoConvergence> {
+        let mut a = self.0;
+
+        balance(&mut a);
+        hessenberg(&mut a);
+        hessenberg_qr(a)
+    }
+}
+
+/// `a` with the sign of `b`
+fn sign<T: Float>(a: T, b: T) -> T {
+    if b >= T::zero() {
+        a.abs()
+    } else {
+        -a.abs()
+    }
+}
+
+/// Scales rows and columns so that they have similar norms, this doesn't change the eigenvalues
+/// but does make them less sensitive to rounding errors
+fn balance<T: Float, const N: usize>(a: &mut [[T; N]; N]) {
+    let radix = T::one() + T::one();
+    let radix_sq = radix * radix;
+    let threshold = T::from(0.95).unwrap();
+
+    let mut done = false;
+
+    while !done {
+        done = true;
+
+        for i in 0..N {
+            let mut c = T::zero();
+            let mut r = T::zero();
+
+            for j in 0..N {
+                if j != i {
+                    c = c + a[j][i].abs();
+                    r = r + a[i][j].abs();
+                }
+            }
+
+            if c.is_zero() || r.is_zero() {
+                continue;
+            }
+
+            let s = c + r;
+            let mut f = T::one();
+
+            let g = r / radix;
+            while c < g {
+                f = f * radix;
+                c = c * radix_sq;
+            }
+
+            let g = r * radix;
+            while c > g {
+                f = f / radix;
+                c = c / radix_sq;
+            }
+
+            if (c + r) / f < threshold * s {
+                done = false;
+
+                a[i].iter_mut().for_each(|x| *x = *x / f);
+                a.iter_mut().for_each(|row| row[i] = row[i] * f);
+            }
+        }
+    }
+}
+
+/// Reduces `a` to upper Hessenberg form with Gaussian elimination, this doesn't change the eigenvalues
+fn hessenberg<T: Float, const N: usize>(a: &mut [[T; N]; N]) {
+    for m in 1..N.saturating_sub(1) {
+        let mut x = T::zero();
+        let mut pivot = m;
+
+        for j in m..N {
+            if a[j][m - 1].abs() > x.abs() {
+                x = a[j][m - 1];
+                pivot = j;
+            }
+        }
+
+        if pivot != m {
+            a.swap(pivot, m);
+            a.iter_mut().for_each(|row| row.swap(pivot, m));
+        }
+
+        if x.is_zero() {
+            continue;
+        }
+
+        for i in m + 1..N {
+            let y = a[i][m - 1];
+
+            if y.is_zero() {
+                continue;
+            }
+
+            let y = y / x;
+
+            a[i][m - 1] = T::zero();
+
+            for j in m..N {
+                a[i][j] = a[i][j] - y * a[m][j];
+            }
+
+            for row in a.iter_mut() {
+                row[m] = row[m] + y * row[i];
+            }
+        }
+    }
+}
+
+/// Finds the eigenvalues of an upper Hessenberg matrix with the Francis double shift QR algorithm
+fn hessenberg_qr<T: Float, const N: usize>(mut a: [[T; N]; N]) -> Result<[Complex<T>; N], NoConvergence> {
+    const MAX_ITERATIONS: usize = 30;
+
+    let zero = T::zero();
+    let eps = T::epsilon();
+    let half = T::from(0.5).unwrap();
+
+    let mut values = [Complex::new(zero, zero); N];
+
+    let mut norm = zero;
+    for (i, row) in a.iter().enumerate() {
+        for x in &row[i.saturating_sub(1)..] {
+            norm = norm + x.abs();
+        }
+    }
+
+    // the accumulated exceptional shifts
+    let mut t = zero;
+    // the number of rows that haven't been deflated yet
+    let mut end = N;
+
+    while end > 0 {
+        let mut its = 0;
+
+        loop {
+            let nn = end - 1;
+
+            // look for a small subdiagonal element to split the matrix at
+            let mut l = nn;
+            while l > 0 {
+                let mut s = a[l - 1][l - 1].abs() + a[l][l].abs();
+
+                if s.is_zero() {
+                    s = norm;
+                }
+
+                if a[l][l - 1].abs() <= eps * s {
+                    a[l][l - 1] = zero;
+                    break;
+                }
+
+                l -= 1;
+            }
+
+            let mut x = a[nn][nn];
+
+            if l == nn {
+                // one root found
+                values[nn] = Complex::new(x + t, zero);
+                end -= 1;
+                break;
+            }
+
+            let mut y = a[nn - 1][nn - 1];
+            let mut w = a[nn][nn - 1] * a[nn - 1][nn];
+
+            if l + 1 == nn {
+                // two roots found
+                let p = half * (y - x);
+                let q = p * p + w;
+                let z = q.abs().sqrt();
+
+                x = x + t;
+
+                if q >= zero {
+                    let z = p + sign(z, p);
+
+                    values[nn - 1] = Complex::new(x + z, zero);
+                    values[nn] = if z.is_zero() {
+                        values[nn - 1]
+                    } else {
+                        Complex::new(x - w / z, zero)
+                    };
+                } else {
+                    values[nn] = Complex::new(x + p, -z);
+                    values[nn - 1] = Complex::new(x + p, z);
+                }
+
+                end -= 2;
+                break;
+            }
+
+            if its == MAX_ITERATIONS {
+                return Err(NoConvergence);
+            }
+
+            if its == 10 || its == 20 {
+                // exceptional shift
+                t = t + x;
+
+                for (i, row) in a.iter_mut().enumerate().take(end) {
+                    row[i] = row[i] - x;
+                }
+
+                let s = a[nn][nn - 1].abs() + a[nn - 1][nn - 2].abs();
+
+                x = T::from(0.75).unwrap() * s;
+                y = x;
+                w = T::from(-0.4375).unwrap() * s * s;
+            }
+
+            its += 1;
+
+            // look for two consecutive small subdiagonal elements
+            let (mut p, mut q, mut r, mut z);
+            let mut m = nn - 2;
+
+            loop {
+                z = a[m][m];
+                r = x - z;
+                let s = y - z;
+                p = (r * s - w) / a[m + 1][m] + a[m][m + 1];
+                q = a[m + 1][m + 1] - z - r - s;
+                r = a[m + 2][m + 1];
+
+                let s = p.abs() + q.abs() + r.abs();
+                p = p / s;
+                q = q / s;
+                r = r / s;
+
+                if m == l {
+                    break;
+                }
+
+                let u = a[m][m - 1].abs() * (q.abs() + r.abs());
+                let v = p.abs() * (a[m - 1][m - 1].abs() + z.abs() + a[m + 1][m + 1].abs());
+
+                if u <= eps * v {
+                    break;
+                }
+
+                m -= 1;
+            }
+
+            for i in m..nn - 1 {
+                a[i + 2][i] = zero;
+
+                if i != m {
+                    a[i + 2][i - 1] = zero;
+                }
+            }
+
+            // double QR step on rows `l..=nn` and columns `m..=nn`
+            for k in m..nn {
+                if k != m {
+                    p = a[k][k - 1];
+                    q = a[k + 1][k - 1];
+                    r = if k + 1 != nn { a[k + 2][k - 1] } else { zero };
+
+                    x = p.abs() + q.abs() + r.abs();
+
+                    if !x.is_zero() {
+                        p = p / x;
+                        q = q / x;
+                        r = r / x;
+                    }
+                }
+
+                let s = sign((p * p + q * q + r * r).sqrt(), p);
+
+                if s.is_zero() {
+                    continue;
+                }
+
+                if k == m {
+                    if l != m {
+                        a[k][k - 1] = -a[k][k - 1];
+                    }
+                } else {
+                    a[k][k - 1] = -s * x;
+                }
+
+                p = p + s;
+                x = p / s;
+                y = q / s;
+                z = r / s;
+                q = q / p;
+                r = r / p;
+
+                // row modification
+                for j in k..=nn {
+                    let mut p = a[k][j] + q * a[k + 1][j];
+
+                    if k + 1 != nn {
+                        p = p + r * a[k + 2][j];
+                        a[k + 2][j] = a[k + 2][j] - p * z;
+                    }
+
+                    a[k + 1][j] = a[k + 1][j] - p * y;
+                    a[k][j] = a[k][j] - p * x;
+                }
+
+                // column modification
+                for row in a.iter_mut().take(nn.min(k + 3) + 1).skip(l) {
+                    let mut p = x * row[k] + y * row[k + 1];
+
+                    if k + 1 != nn {
+                        p = p + z * row[k + 2];
+                        row[k + 2] = row[k + 2] - p * r;
+                    }
+
+                    row[k + 1] = row[k + 1] - p * q;
+                    row[k] = row[k] - p;
+                }
+            }
+        }
+    }
+
+    Ok(values)
+}
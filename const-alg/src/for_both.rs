@@ -4,6 +4,12 @@ use crate::{into_iter, Matrix};
 /// 
 /// This takes a secondary matrix, and a function that accepts two arguments,
 /// elements into each matrix, and calls the function with all of the corrosponding elements of each matrix
+/// 
+/// # Panic safety
+/// 
+/// Like `ForEach`, nothing is produced. If the function panics, the remaining elements of each matrix
+/// that was taken by value are dropped, independently of how the other one was taken,
+/// and changes made through a `&mut` matrix before the panic are kept. See the [crate docs](crate#panic-safety)
 pub trait ForBoth<RHS, F> {
     fn for_both(self, other: RHS, f: F);
 }
@@ -3,6 +3,12 @@ use crate::{into_iter, Matrix};
 /// Calls a function eagerly on all elements of a matrix
 /// 
 /// This takes a function that accepts items from the matrix, and calls it on all elements of the matrix
+/// 
+/// # Panic safety
+/// 
+/// Nothing is produced, so only the inputs matter. If the function panics on a matrix taken by value,
+/// the elements it hasn't been called on yet are dropped. On a `&mut` matrix,
+/// the changes made before the panic are kept. See the [crate docs](crate#panic-safety)
 pub trait ForEach<F> {
     fn for_each(self, f: F);
 }
@@ -0,0 +1,274 @@
+use crate::{Matrix, SquareMatrix};
+use num::{Float, One};
+
+use std::cmp::Ordering;
+
+impl<T: Float, const N: usize> Matrix<T, { N }, { N }> {
+    /// Calculates the inverse of the matrix
+    ///
+    /// If the matrix is singular, `None` is returned. A matrix is considered singular if the
+    /// absolute value of its determinant is not greater than `T::epsilon()` times `s^N`, where `s` is
+    /// the largest absolute value of its elements. So scaling a matrix doesn't change if it is singular,
+    /// use `try_inverse_with` to pick a different tolerance
+    pub fn try_inverse(&self) -> Option<Self> {
+        self.try_inverse_with(T::epsilon())
+    }
+
+    /// Calculates the inverse of the matrix
+    ///
+    /// If the absolute value of the determinant is not greater than `tol * s^N`, `None` is returned,
+    /// where `s` is the largest absolute value of the elements
+    pub fn try_inverse_with(&self, tol: T) -> Option<Self> {
+        let mut inv = *self;
+
+        if inv.invert_with(tol) {
+            Some(inv)
+        } else {
+            None
+        }
+    }
+
+    /// Inverts the matrix in place
+    ///
+    /// Returns false and leaves the matrix unchanged if it is singular,
+    /// see `try_inverse` for what counts as singular
+    pub fn invert(&mut self) -> bool {
+        self.invert_with(T::epsilon())
+    }
+
+    /// Inverts the matrix in place
+    ///
+    /// Returns false and leaves the matrix unchanged if the absolute value of the determinant
+    /// is not greater than `tol * s^N`, where `s` is the largest absolute value of the elements
+    ///
+    /// Matricies up to `4 x 4` use the closed form of the inverse, larger ones use Gauss-Jordan elimination
+    pub fn invert_with(&mut self, tol: T) -> bool {
+        let scale = self
+            .iter()
+            .flat_map(|row| row.iter())
+            .fold(T::zero(), |acc, x| acc.max(x.abs()));
+
+        let tol = Tolerance { tol, scale };
+
+        match N {
+            2 => invert_2(self, tol),
+            3 => invert_3(self, tol),
+            4 => invert_4(self, tol),
+            _ => gauss_jordan(self, tol),
+        }
+    }
+}
+
+/// The tolerance for singular matricies, relative to the largest absolute value of the elements
+#[derive(Clone, Copy)]
+struct Tolerance<T> {
+    tol: T,
+    scale: T,
+}
+
+impl<T: Float> Tolerance<T> {
+    /// Checks if `|det| <= tol * scale^N`
+    ///
+    /// The determinant is divided by the scale one factor at a time, so this doesn't overflow or underflow
+    /// for matricies with very large or small elements
+    fn is_singular<const N: usize>(&self, det: T) -> bool {
+        self.is_negligible((0..N).fold(det, |det, _| det / self.scale))
+    }
+
+    /// Checks if the determinant of the matrix divided by `scale`, `det / scale^N`, is not greater than `tol`
+    ///
+    /// A zero matrix has a scale of zero, which makes this NaN
+    fn is_negligible(&self, scaled_det: T) -> bool {
+        scaled_det.is_nan() || scaled_det.abs() <= self.tol
+    }
+}
+
+fn invert_2<T: Float, const N: usize>(mat: &mut SquareMatrix<T, { N }>, tol: Tolerance<T>) -> bool {
+    let a = |i, j| mat[(i, j)];
+
+    let det = a(0, 0) * a(1, 1) - a(0, 1) * a(1, 0);
+
+    if tol.is_singular::<{ N }>(det) {
+        return false;
+    }
+
+    let inv = [[a(1, 1), -a(0, 1)], [-a(1, 0), a(0, 0)]];
+
+    write_scaled(mat, inv, det);
+
+    true
+}
+
+fn invert_3<T: Float, const N: usize>(mat: &mut SquareMatrix<T, { N }>, tol: Tolerance<T>) -> bool {
+    let a = |i, j| mat[(i, j)];
+
+    let c00 = a(1, 1) * a(2, 2) - a(1, 2) * a(2, 1);
+    let c01 = a(1, 2) * a(2, 0) - a(1, 0) * a(2, 2);
+    let c02 = a(1, 0) * a(2, 1) - a(1, 1) * a(2, 0);
+
+    let det = a(0, 0) * c00 + a(0, 1) * c01 + a(0, 2) * c02;
+
+    if tol.is_singular::<{ N }>(det) {
+        return false;
+    }
+
+    let inv = [
+        [
+            c00,
+            a(0, 2) * a(2, 1) - a(0, 1) * a(2, 2),
+            a(0, 1) * a(1, 2) - a(0, 2) * a(1, 1),
+        ],
+        [
+            c01,
+            a(0, 0) * a(2, 2) - a(0, 2) * a(2, 0),
+            a(0, 2) * a(1, 0) - a(0, 0) * a(1, 2),
+        ],
+        [
+            c02,
+            a(0, 1) * a(2, 0) - a(0, 0) * a(2, 1),
+            a(0, 0) * a(1, 1) - a(0, 1) * a(1, 0),
+        ],
+    ];
+
+    write_scaled(mat, inv, det);
+
+    true
+}
+
+fn invert_4<T: Float, const N: usize>(mat: &mut SquareMatrix<T, { N }>, tol: Tolerance<T>) -> bool {
+    let a = |i, j| mat[(i, j)];
+
+    // 2x2 minors of the top two rows
+    let s0 = a(0, 0) * a(1, 1) - a(1, 0) * a(0, 1);
+    let s1 = a(0, 0) * a(1, 2) - a(1, 0) * a(0, 2);
+    let s2 = a(0, 0) * a(1, 3) - a(1, 0) * a(0, 3);
+    let s3 = a(0, 1) * a(1, 2) - a(1, 1) * a(0, 2);
+    let s4 = a(0, 1) * a(1, 3) - a(1, 1) * a(0, 3);
+    let s5 = a(0, 2) * a(1, 3) - a(1, 2) * a(0, 3);
+
+    // 2x2 minors of the bottom two rows
+    let c5 = a(2, 2) * a(3, 3) - a(3, 2) * a(2, 3);
+    let c4 = a(2, 1) * a(3, 3) - a(3, 1) * a(2, 3);
+    let c3 = a(2, 1) * a(3, 2) - a(3, 1) * a(2, 2);
+    let c2 = a(2, 0) * a(3, 3) - a(3, 0) * a(2, 3);
+    let c1 = a(2, 0) * a(3, 2) - a(3, 0) * a(2, 2);
+    let c0 = a(2, 0) * a(3, 1) - a(3, 0) * a(2, 1);
+
+    let det = s0 * c5 - s1 * c4 + s2 * c3 + s3 * c2 - s4 * c1 + s5 * c0;
+
+    if tol.is_singular::<{ N }>(det) {
+        return false;
+    }
+
+    let inv = [
+        [
+            a(1, 1) * c5 - a(1, 2) * c4 + a(1, 3) * c3,
+            -a(0, 1) * c5 + a(0, 2) * c4 - a(0, 3) * c3,
+            a(3, 1) * s5 - a(3, 2) * s4 + a(3, 3) * s3,
+            -a(2, 1) * s5 + a(2, 2) * s4 - a(2, 3) * s3,
+        ],
+        [
+            -a(1, 0) * c5 + a(1, 2) * c2 - a(1, 3) * c1,
+            a(0, 0) * c5 - a(0, 2) * c2 + a(0, 3) * c1,
+            -a(3, 0) * s5 + a(3, 2) * s2 - a(3, 3) * s1,
+            a(2, 0) * s5 - a(2, 2) * s2 + a(2, 3) * s1,
+        ],
+        [
+            a(1, 0) * c4 - a(1, 1) * c2 + a(1, 3) * c0,
+            -a(0, 0) * c4 + a(0, 1) * c2 - a(0, 3) * c0,
+            a(3, 0) * s4 - a(3, 1) * s2 + a(3, 3) * s0,
+            -a(2, 0) * s4 + a(2, 1) * s2 - a(2, 3) * s0,
+        ],
+        [
+            -a(1, 0) * c3 + a(1, 1) * c1 - a(1, 2) * c0,
+            a(0, 0) * c3 - a(0, 1) * c1 + a(0, 2) * c0,
+            -a(3, 0) * s3 + a(3, 1) * s1 - a(3, 2) * s0,
+            a(2, 0) * s3 - a(2, 1) * s1 + a(2, 2) * s0,
+        ],
+    ];
+
+    write_scaled(mat, inv, det);
+
+    true
+}
+
+/// Writes `adj / det` into `mat`, `mat` must be `K x K`
+fn write_scaled<T: Float, const N: usize, const K: usize>(
+    mat: &mut SquareMatrix<T, { N }>,
+    adj: [[T; K]; K],
+    det: T,
+) {
+    for (row, adj_row) in mat.rows_mut().zip(adj.iter()) {
+        row.zip(adj_row.iter()).for_each(|(x, &y)| *x = y / det);
+    }
+}
+
+fn gauss_jordan<T: Float, const N: usize>(
+    mat: &mut SquareMatrix<T, { N }>,
+    tol: Tolerance<T>,
+) -> bool {
+    let mut a = *mat;
+    let mut inv = SquareMatrix::<T, { N }>::one();
+    let mut det = T::one();
+
+    for k in 0..N {
+        let p = (k..N)
+            .max_by(|&x, &y| {
+                a[(x, k)]
+                    .abs()
+                    .partial_cmp(&a[(y, k)].abs())
+                    .unwrap_or(Ordering::Equal)
+            })
+            .unwrap_or(k);
+
+        let pivot = a[(p, k)];
+
+        if pivot.is_zero() {
+            return false;
+        }
+
+        if p != k {
+            a.swap(p, k);
+            inv.swap(p, k);
+            det = -det;
+        }
+
+        det = det * (pivot / tol.scale);
+
+        a.0[k].iter_mut().for_each(|x| *x = *x / pivot);
+        inv.0[k].iter_mut().for_each(|x| *x = *x / pivot);
+
+        let (a_k, inv_k) = (a.0[k], inv.0[k]);
+
+        let mut factors = [T::zero(); N];
+        for (factor, row) in factors.iter_mut().zip(a.iter()) {
+            *factor = row[k];
+        }
+
+        let rows = a.rows_mut().zip(inv.rows_mut());
+
+        for (i, (a_row, inv_row)) in rows.enumerate() {
+            if i == k {
+                continue;
+            }
+
+            let factor = factors[i];
+
+            a_row
+                .zip(a_k.iter())
+                .for_each(|(x, &y)| *x = *x - factor * y);
+            inv_row
+                .zip(inv_k.iter())
+                .for_each(|(x, &y)| *x = *x - factor * y);
+        }
+    }
+
+    // `det` was already scaled while it was accumulated
+    if tol.is_negligible(det) {
+        return false;
+    }
+
+    *mat = inv;
+
+    true
+}
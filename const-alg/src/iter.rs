@@ -23,6 +23,8 @@ pub struct IntoRows<T, const N: usize, const M: usize> {
 #[derive(Clone)]
 pub struct IntoCols<T, const N: usize, const M: usize> {
     arr: ArrayVec<IntoIter<T, { M }>, { N }>,
+    // the number of columns left, this can't be read from `arr` if there are no rows
+    cols: usize,
 }
 
 /// An iterator over the unique references to a single row of a matrix
@@ -30,24 +32,28 @@ pub struct RowMut<'a, T, const N: usize, const M: usize>(IterMut<'a, T>);
 
 /// An iterator over the unique references to rows  of a matrix
 pub struct RowsMut<'a, T, const N: usize, const M: usize> {
-    inner: IterMut<'a, [T; M]>,
+    inner: &'a mut [T],
+    stride: usize,
+    rows: usize,
 }
 
 /// An iterator over the unique references to a single column of a matrix
 pub struct ColMut<'a, T, const N: usize, const M: usize> {
-    inner: *mut [[T; M]; N],
+    inner: *mut T,
+    stride: usize,
     col: usize,
     row: usize,
     row_end: usize,
-    lt: PhantomData<&'a mut [[T; M]; N]>,
+    lt: PhantomData<&'a mut T>,
 }
 
 /// An iterator over the unique references to the columns of a matrix
 pub struct ColsMut<'a, T, const N: usize, const M: usize> {
-    inner: *mut [[T; M]; N],
+    inner: *mut T,
+    stride: usize,
     col: usize,
     col_end: usize,
-    lt: PhantomData<&'a mut [[T; M]; N]>,
+    lt: PhantomData<&'a mut T>,
 }
 
 /// An iterator over the shared references to a single row of a matrix
@@ -55,12 +61,16 @@ pub struct Row<'a, T, const N: usize, const M: usize>(Iter<'a, T>);
 
 /// An iterator over the shared references to rows  of a matrix
 pub struct Rows<'a, T, const N: usize, const M: usize> {
-    inner: Iter<'a, [T; M]>,
+    inner: &'a [T],
+    stride: usize,
+    row: usize,
+    row_end: usize,
 }
 
 /// An iterator over the shared references to a single column of a matrix
 pub struct Col<'a, T, const N: usize, const M: usize> {
-    inner: &'a [[T; M]; N],
+    inner: &'a [T],
+    stride: usize,
     col: usize,
     row: usize,
     row_end: usize,
@@ -68,11 +78,104 @@ pub struct Col<'a, T, const N: usize, const M: usize> {
 
 /// An iterator over the shared references to the columns of a matrix
 pub struct Cols<'a, T, const N: usize, const M: usize> {
-    inner: &'a [[T; M]; N],
+    inner: &'a [T],
+    stride: usize,
     col: usize,
     col_end: usize,
 }
 
+// `RowsMut`, `Rows`, and `Cols` work on an `N x M` block of elements stored in row-major order,
+// where each row starts `stride` elements after the last one, this way they can be used both for
+// whole matricies (where `stride == M`) and for views into matricies
+
+impl<'a, T, const N: usize, const M: usize> RowsMut<'a, T, { N }, { M }> {
+    pub(crate) fn new(inner: &'a mut [T], stride: usize) -> Self {
+        Self {
+            inner,
+            stride,
+            rows: N,
+        }
+    }
+}
+
+// The columns can't be split apart as slices, so `ColsMut` and `ColMut` only keep a raw pointer
+// to the start of the block, and only ever make references to the single elements they yield.
+// Each column is only yielded once by `ColsMut`, and each row only once by `ColMut`,
+// so no element is ever yielded twice
+
+unsafe impl<T: Send, const N: usize, const M: usize> Send for ColMut<'_, T, { N }, { M }> {}
+unsafe impl<T: Sync, const N: usize, const M: usize> Sync for ColMut<'_, T, { N }, { M }> {}
+unsafe impl<T: Send, const N: usize, const M: usize> Send for ColsMut<'_, T, { N }, { M }> {}
+unsafe impl<T: Sync, const N: usize, const M: usize> Sync for ColsMut<'_, T, { N }, { M }> {}
+
+impl<'a, T, const N: usize, const M: usize> ColMut<'a, T, { N }, { M }> {
+    /// Safety: `row` must be less than `N`
+    unsafe fn get(&self, row: usize) -> &'a mut T {
+        &mut *self.inner.add(row * self.stride + self.col)
+    }
+}
+
+impl<'a, T, const N: usize, const M: usize> ColsMut<'a, T, { N }, { M }> {
+    pub(crate) fn new(inner: &'a mut [T], stride: usize) -> Self {
+        Self {
+            inner: inner.as_mut_ptr(),
+            stride,
+            col: 0,
+            col_end: M,
+            lt: PhantomData,
+        }
+    }
+
+    fn get(&self, col: usize) -> ColMut<'a, T, { N }, { M }> {
+        ColMut {
+            inner: self.inner,
+            stride: self.stride,
+            col,
+            row: 0,
+            row_end: N,
+            lt: PhantomData,
+        }
+    }
+}
+
+impl<'a, T, const N: usize, const M: usize> Rows<'a, T, { N }, { M }> {
+    pub(crate) fn new(inner: &'a [T], stride: usize) -> Self {
+        Self {
+            inner,
+            stride,
+            row: 0,
+            row_end: N,
+        }
+    }
+
+    fn get(&self, row: usize) -> Row<'a, T, { N }, { M }> {
+        let start = row * self.stride;
+
+        Row(self.inner[start..start + M].iter())
+    }
+}
+
+impl<'a, T, const N: usize, const M: usize> Cols<'a, T, { N }, { M }> {
+    pub(crate) fn new(inner: &'a [T], stride: usize) -> Self {
+        Self {
+            inner,
+            stride,
+            col: 0,
+            col_end: M,
+        }
+    }
+
+    fn get(&self, col: usize) -> Col<'a, T, { N }, { M }> {
+        Col {
+            inner: self.inner,
+            stride: self.stride,
+            col,
+            row: 0,
+            row_end: N,
+        }
+    }
+}
+
 impl<T, const N: usize> Iterator for IntoLine<T, { N }> {
     type Item = T;
 
@@ -95,7 +198,7 @@ impl<T, const N: usize> DoubleEndedIterator for IntoLine<T, { N }> {
     }
 
     fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
-        self.0.nth(n)
+        self.0.nth_back(n)
     }
 }
 
@@ -135,6 +238,8 @@ impl<T, const N: usize, const M: usize> Iterator for IntoCols<T, { N }, { M }> {
     type Item = IntoLine<T, { N }>;
 
     fn next(&mut self) -> Option<Self::Item> {
+        self.cols = self.cols.checked_sub(1)?;
+
         self.arr
             .iter_mut()
             .map(|x| x.next())
@@ -144,6 +249,9 @@ impl<T, const N: usize, const M: usize> Iterator for IntoCols<T, { N }, { M }> {
     }
 
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.cols = self.cols.saturating_sub(n);
+        self.cols = self.cols.checked_sub(1)?;
+
         self.arr
             .iter_mut()
             .map(|x| x.nth(n))
@@ -153,16 +261,14 @@ impl<T, const N: usize, const M: usize> Iterator for IntoCols<T, { N }, { M }> {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        if N == 0 {
-            (0, Some(0))
-        } else {
-            self.arr[0].size_hint()
-        }
+        (self.cols, Some(self.cols))
     }
 }
 
 impl<T, const N: usize, const M: usize> DoubleEndedIterator for IntoCols<T, { N }, { M }> {
     fn next_back(&mut self) -> Option<Self::Item> {
+        self.cols = self.cols.checked_sub(1)?;
+
         self.arr
             .iter_mut()
             .map(|x| x.next_back())
@@ -172,6 +278,9 @@ impl<T, const N: usize, const M: usize> DoubleEndedIterator for IntoCols<T, { N
     }
 
     fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.cols = self.cols.saturating_sub(n);
+        self.cols = self.cols.checked_sub(1)?;
+
         self.arr
             .iter_mut()
             .map(|x| x.nth_back(n))
@@ -217,25 +326,67 @@ impl<'a, T, const N: usize, const M: usize> Iterator for RowsMut<'a, T, { N }, {
     type Item = RowMut<'a, T, { N }, { M }>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.inner.next().map(|x| RowMut(x.iter_mut()))
+        if self.rows == 0 {
+            return None;
+        }
+
+        let inner = std::mem::take(&mut self.inner);
+        let (row, rest) = inner.split_at_mut(self.stride.min(inner.len()));
+
+        self.inner = rest;
+        self.rows -= 1;
+
+        Some(RowMut(row[..M].iter_mut()))
     }
 
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
-        self.inner.nth(n).map(|x| RowMut(x.iter_mut()))
+        if n >= self.rows {
+            self.inner = &mut [];
+            self.rows = 0;
+            return None;
+        }
+
+        let inner = std::mem::take(&mut self.inner);
+
+        self.inner = &mut inner[n * self.stride..];
+        self.rows -= n;
+        self.next()
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.inner.size_hint()
+        (self.rows, Some(self.rows))
     }
 }
 
 impl<'a, T, const N: usize, const M: usize> DoubleEndedIterator for RowsMut<'a, T, { N }, { M }> {
     fn next_back(&mut self) -> Option<Self::Item> {
-        self.inner.next_back().map(|x| RowMut(x.iter_mut()))
+        if self.rows == 0 {
+            return None;
+        }
+
+        let inner = std::mem::take(&mut self.inner);
+        let (rest, row) = inner.split_at_mut((self.rows - 1) * self.stride);
+
+        self.inner = rest;
+        self.rows -= 1;
+
+        Some(RowMut(row[..M].iter_mut()))
     }
 
     fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
-        self.inner.nth_back(n).map(|x| RowMut(x.iter_mut()))
+        if n >= self.rows {
+            self.inner = &mut [];
+            self.rows = 0;
+            return None;
+        }
+
+        self.rows -= n;
+
+        let inner = std::mem::take(&mut self.inner);
+        let len = inner.len().min(self.rows * self.stride);
+
+        self.inner = &mut inner[..len];
+        self.next_back()
     }
 }
 
@@ -243,18 +394,14 @@ impl<T, const N: usize, const M: usize> ExactSizeIterator for RowsMut<'_, T, { N
 impl<T, const N: usize, const M: usize> FusedIterator for RowsMut<'_, T, { N }, { M }> {}
 
 impl<'a, T, const N: usize, const M: usize> Iterator for ColMut<'a, T, { N }, { M }> {
-    type Item = &'a T;
+    type Item = &'a mut T;
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.row == self.row_end {
             return None;
         }
 
-        let val = unsafe {
-            (*self.inner)
-                .get_unchecked(self.row)
-                .get_unchecked(self.col)
-        };
+        let val = unsafe { self.get(self.row) };
 
         self.row += 1;
 
@@ -289,13 +436,7 @@ impl<'a, T, const N: usize, const M: usize> DoubleEndedIterator for ColMut<'a, T
 
         self.row_end -= 1;
 
-        unsafe {
-            Some(
-                (*self.inner)
-                    .get_unchecked(self.row_end)
-                    .get_unchecked(self.col),
-            )
-        }
+        unsafe { Some(self.get(self.row_end)) }
     }
 
     fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
@@ -323,13 +464,7 @@ impl<'a, T, const N: usize, const M: usize> Iterator for ColsMut<'a, T, { N }, {
             return None;
         }
 
-        let col = ColMut {
-            inner: self.inner,
-            col: self.col,
-            row: 0,
-            row_end: N,
-            lt: PhantomData,
-        };
+        let col = self.get(self.col);
 
         self.col += 1;
 
@@ -364,13 +499,7 @@ impl<'a, T, const N: usize, const M: usize> DoubleEndedIterator for ColsMut<'a,
 
         self.col_end -= 1;
 
-        Some(ColMut {
-            inner: self.inner,
-            col: self.col_end,
-            row: 0,
-            row_end: N,
-            lt: PhantomData,
-        })
+        Some(self.get(self.col_end))
     }
 
     fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
@@ -423,25 +552,59 @@ impl<'a, T, const N: usize, const M: usize> Iterator for Rows<'a, T, { N }, { M
     type Item = Row<'a, T, { N }, { M }>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.inner.next().map(|x| Row(x.iter()))
+        if self.row == self.row_end {
+            return None;
+        }
+
+        let row = self.get(self.row);
+
+        self.row += 1;
+
+        Some(row)
     }
 
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
-        self.inner.nth(n).map(|x| Row(x.iter()))
+        match self.row.checked_add(n) {
+            Some(row) if row < self.row_end => {
+                self.row = row;
+                self.next()
+            }
+            _ => {
+                self.row = self.row_end;
+                None
+            }
+        }
     }
-    
+
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.inner.size_hint()
+        let size = self.row_end - self.row;
+
+        (size, Some(size))
     }
 }
 
 impl<'a, T, const N: usize, const M: usize> DoubleEndedIterator for Rows<'a, T, { N }, { M }> {
     fn next_back(&mut self) -> Option<Self::Item> {
-        self.inner.next_back().map(|x| Row(x.iter()))
+        if self.row == self.row_end {
+            return None;
+        }
+
+        self.row_end -= 1;
+
+        Some(self.get(self.row_end))
     }
 
     fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
-        self.inner.nth_back(n).map(|x| Row(x.iter()))
+        match self.row_end.checked_sub(n) {
+            Some(row_end) if self.row < row_end => {
+                self.row_end = row_end;
+                self.next_back()
+            }
+            _ => {
+                self.row = self.row_end;
+                None
+            }
+        }
     }
 }
 
@@ -456,7 +619,7 @@ impl<'a, T, const N: usize, const M: usize> Iterator for Col<'a, T, { N }, { M }
             return None;
         }
 
-        let val = unsafe { self.inner.get_unchecked(self.row).get_unchecked(self.col) };
+        let val = unsafe { self.inner.get_unchecked(self.row * self.stride + self.col) };
 
         self.row += 1;
 
@@ -494,8 +657,7 @@ impl<'a, T, const N: usize, const M: usize> DoubleEndedIterator for Col<'a, T, {
         unsafe {
             Some(
                 self.inner
-                    .get_unchecked(self.row_end)
-                    .get_unchecked(self.col),
+                    .get_unchecked(self.row_end * self.stride + self.col),
             )
         }
     }
@@ -525,12 +687,7 @@ impl<'a, T, const N: usize, const M: usize> Iterator for Cols<'a, T, { N }, { M
             return None;
         }
 
-        let col = Col {
-            inner: self.inner,
-            col: self.col,
-            row: 0,
-            row_end: N,
-        };
+        let col = self.get(self.col);
 
         self.col += 1;
 
@@ -565,14 +722,7 @@ impl<'a, T, const N: usize, const M: usize> DoubleEndedIterator for Cols<'a, T,
 
         self.col_end -= 1;
 
-        let col = Col {
-            inner: self.inner,
-            col: self.col_end,
-            row: 0,
-            row_end: N,
-        };
-
-        Some(col)
+        Some(self.get(self.col_end))
     }
 
     fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
@@ -604,40 +754,28 @@ impl<T, const N: usize, const M: usize> Matrix<T, { N }, { M }> {
     pub fn into_cols(self) -> IntoCols<T, { N }, { M }> {
         IntoCols {
             arr: into_iter(self.0).map(into_iter).collect(),
+            cols: M,
         }
     }
 
     /// iterates over unique references to rows of a matrix
-    pub fn rows_mut(&mut self) -> RowsMut<T, { N }, { M }> {
-        RowsMut {
-            inner: self.0.iter_mut(),
-        }
+    pub fn rows_mut(&mut self) -> RowsMut<'_, T, { N }, { M }> {
+        RowsMut::new(self.flatten_mut(), M)
     }
 
     /// iterates over unique references to columns of a matrix
-    pub fn cols_mut(&mut self) -> ColsMut<T, { N }, { M }> {
-        ColsMut {
-            inner: &mut self.0,
-            col: 0,
-            col_end: N,
-            lt: PhantomData,
-        }
+    pub fn cols_mut(&mut self) -> ColsMut<'_, T, { N }, { M }> {
+        ColsMut::new(self.flatten_mut(), M)
     }
 
     /// iterates over shared references to rows of a matrix
-    pub fn rows(&self) -> Rows<T, { N }, { M }> {
-        Rows {
-            inner: self.0.iter(),
-        }
+    pub fn rows(&self) -> Rows<'_, T, { N }, { M }> {
+        Rows::new(self.flatten(), M)
     }
 
     /// iterates over shared references to columns of a matrix
-    pub fn cols(&self) -> Cols<T, { N }, { M }> {
-        Cols {
-            inner: &self.0,
-            col: 0,
-            col_end: N,
-        }
+    pub fn cols(&self) -> Cols<'_, T, { N }, { M }> {
+        Cols::new(self.flatten(), M)
     }
 }
 
@@ -655,9 +793,7 @@ impl<'a, T, const N: usize, const M: usize> Clone for Col<'a, T, { N }, { M }> {
 
 impl<'a, T, const N: usize, const M: usize> Clone for Rows<'a, T, { N }, { M }> {
     fn clone(&self) -> Self {
-        Self {
-            inner: self.inner.clone(),
-        }
+        Self { ..*self }
     }
 }
 
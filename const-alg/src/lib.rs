@@ -1,4 +1,3 @@
-#![feature(const_generics, specialization, existential_type)]
 #![allow(unused_unsafe)]
 // #![forbid(missing_docs)]
 
@@ -10,6 +9,7 @@
  * The syntax for an matrix is just,
  * 
  * ```rust
+ * # use const_alg::Matrix;
  * let m = Matrix(
  *      [[0, 1, 2],
  *       [3, 4, 5]]
@@ -17,6 +17,12 @@
  * ```
  * 
  * For a 2x3 matrix! This will work with any number of rows and columns!
+ * 
+ * # Panic safety
+ * 
+ * The eager combinators (`Map`, `ZipWith`, `ForEach` and `ForBoth`) visit the elements in row-major order,
+ * and if the function passed to them panics, nothing is leaked and nothing is dropped twice.
+ * Each trait says which values get dropped while unwinding
  */
 
 use std::ops::{
@@ -42,6 +48,46 @@ pub use for_each::ForEach;
 mod for_both;
 pub use for_both::ForBoth;
 
+mod det;
+pub use det::Determinant;
+
+mod inverse;
+
+mod lu;
+pub use lu::Lu;
+
+mod qr;
+pub use qr::Qr;
+
+mod cholesky;
+pub use cholesky::Cholesky;
+
+mod eigen;
+pub use eigen::{JacobiOptions, NoConvergence, SortOrder, SymmetricEigen};
+
+mod svd;
+pub use svd::Svd;
+
+mod vector;
+pub use vector::{ColVector, RowVector};
+
+mod view;
+pub use view::{MatrixView, MatrixViewMut};
+
+mod block;
+
+mod display;
+pub use display::{DisplayOptions, MatrixDisplay};
+
+mod parse;
+pub use parse::ParseMatrixError;
+
+mod const_fn;
+pub use const_fn::ConstNum;
+
+#[cfg(feature = "serde")]
+mod serde_impl;
+
 #[cfg(test)]
 mod test;
 
@@ -49,50 +95,60 @@ fn into_iter<T, const N: usize>(arr: [T; N]) -> array_vec::IntoIter<T, { N }> {
     ArrayVec::<T, { N }>::from(arr).into_iter()
 }
 
-cfg_if::cfg_if! {
-    if #[cfg(any(debug_assertions))] {
-        use std::convert::TryInto;
-
-        // This branch panics if something goes wrong, and is perfectly safe
-        // With this, the only unsafty is in `Matrix::get_all_mut`
-        // Which is safe as described in it's docs
-
-        fn collect_array<I, T, const N: usize>(iter: I) -> [T; N] where I: IntoIterator<Item = T> {
-            to_array(
-                iter.into_iter().collect::<ArrayVec<T, { N }>>()
-            )
-        }
-        fn to_array<T, const N: usize>(arr: ArrayVec<T, {N}>) -> [T; N] {
-            arr.try_into()
-                .ok()
-                .expect("iterator wasn't long enough")
-        }
+// This version panics if something goes wrong, and is perfectly safe
+// With this, the only unsafty is in `Matrix::get_all_mut`
+// Which is safe as described in it's docs
+#[cfg_attr(not(debug_assertions), allow(dead_code))]
+mod checked {
+    use super::{ArrayVec, Matrix};
+    use std::convert::TryInto;
 
+    pub(super) fn collect_array<I, T, const N: usize>(iter: I) -> [T; N] where I: IntoIterator<Item = T> {
+        to_array(
+            iter.into_iter().collect::<ArrayVec<T, { N }>>()
+        )
+    }
 
-        fn collect_mat<I, T, const N: usize, const M: usize>(iter: I) -> Matrix<T, {N}, {M}> where I: IntoIterator<Item = [T; M]> {
-            Matrix(collect_array(iter))
-        }
+    pub(super) fn to_array<T, const N: usize>(arr: ArrayVec<T, {N}>) -> [T; N] {
+        arr.try_into()
+            .ok()
+            .expect("iterator wasn't long enough")
+    }
 
-    } else {
+    pub(super) fn collect_mat<I, T, const N: usize, const M: usize>(iter: I) -> Matrix<T, {N}, {M}> where I: IntoIterator<Item = [T; M]> {
+        Matrix(collect_array(iter))
+    }
+}
 
-        // This branch causes UB if something goes wrong, and is very unsafe
-        // But because the lengths of all of the arrays are known ahead of time
-        // it is easy to check for soundness
+// This version causes UB if something goes wrong, and is very unsafe
+// But because the lengths of all of the arrays are known ahead of time
+// it is easy to check for soundness
+#[cfg_attr(debug_assertions, allow(dead_code))]
+mod unchecked {
+    use super::{ArrayVec, Matrix};
 
-        unsafe fn collect_array<I, T, const N: usize>(iter: I) -> [T; N] where I: IntoIterator<Item = T> {
-            to_array(
-                iter.into_iter().collect::<ArrayVec<T, { N }>>()
-            )
-        }
+    pub(super) unsafe fn collect_array<I, T, const N: usize>(iter: I) -> [T; N] where I: IntoIterator<Item = T> {
+        to_array(
+            iter.into_iter().collect::<ArrayVec<T, { N }>>()
+        )
+    }
 
-        unsafe fn to_array<T, const N: usize>(arr: ArrayVec<T, {N}>) -> [T; N] {
-            arr.into_array_unchecked()
-        }
+    pub(super) unsafe fn to_array<T, const N: usize>(arr: ArrayVec<T, {N}>) -> [T; N] {
+        arr.into_array_unchecked()
+    }
 
-        unsafe fn collect_mat<I, T, const N: usize, const M: usize>(iter: I) -> Matrix<T, {N}, {M}> where I: IntoIterator<Item = [T; M]> {
-            Matrix(collect_array(iter))
-        }
+    pub(super) unsafe fn collect_mat<I, T, const N: usize, const M: usize>(iter: I) -> Matrix<T, {N}, {M}> where I: IntoIterator<Item = [T; M]> {
+        Matrix(collect_array(iter))
+    }
+}
 
+// Both versions are always compiled so that the tests can check both of them,
+// but the rest of the crate only uses one of them
+cfg_if::cfg_if! {
+    if #[cfg(any(debug_assertions))] {
+        use checked::{collect_array, collect_mat, to_array};
+    } else {
+        use unchecked::{collect_array, collect_mat, to_array};
     }
 }
 
@@ -101,10 +157,11 @@ cfg_if::cfg_if! {
 /// So when you go to make it, you can just do
 /// 
 /// ```
-/// Matrix(
+/// # use const_alg::{Matrix, SquareMatrix};
+/// let m: SquareMatrix<_, 2> = Matrix(
 ///     [[0, 1],
 ///      [3, 4]]
-/// )
+/// );
 /// ```
 /// 
 /// And Rust will take care of the rest!
@@ -115,10 +172,11 @@ pub type SquareMatrix<T, const N: usize> = Matrix<T, { N }, { N }>;
 /// So when you go to make it, you can just do
 /// 
 /// ```
-/// Matrix(
+/// # use const_alg::Matrix;
+/// let m = Matrix(
 ///     [[0, 1, 2],
 ///      [3, 4, 5]]
-/// )
+/// );
 /// ```
 /// 
 /// And Rust will take care of the rest!
@@ -132,16 +190,16 @@ impl<T, const N: usize, const M: usize> From<[[T; M]; N]> for Matrix<T, { N }, {
     }
 }
 
-impl<T, const N: usize, const M: usize> Into<[[T; M]; N]> for Matrix<T, { N }, { M }> {
-    fn into(self) -> [[T; M]; N] {
-        self.0
+impl<T, const N: usize, const M: usize> From<Matrix<T, { N }, { M }>> for [[T; M]; N] {
+    fn from(mat: Matrix<T, { N }, { M }>) -> Self {
+        mat.0
     }
 }
 
 impl<T: Zero, const N: usize, const M: usize> Zero for Matrix<T, { N }, { M }> {
     fn zero() -> Self {
         let zeros = std::iter::repeat_with(T::zero);
-        let rows = std::iter::repeat_with(|| -> [T; M] { unsafe { collect_array(zeros.clone()) } });
+        let rows = std::iter::repeat_with(|| -> [T; M] { unsafe { collect_array(zeros) } });
 
         unsafe { collect_mat(rows) }
     }
@@ -248,9 +306,9 @@ where
     }
 }
 
-fn dot<'a, T: 'a, I: IntoIterator<Item = &'a T>, J: IntoIterator<Item = &'a T>>(a: I, b: J) -> T
+fn dot<'a, I: IntoIterator<Item = &'a T>, J: IntoIterator<Item = &'a T>, T>(a: I, b: J) -> T
 where
-    T: Add<Output = T> + Mul<Output = T> + Zero + Clone,
+    T: 'a + Add<Output = T> + Mul<Output = T> + Zero + Clone,
 {
     a.into_iter()
         .cloned()
@@ -347,6 +405,16 @@ cfg_if::cfg_if! {
 }
 
 impl<T, const N: usize, const M: usize> Matrix<T, { N }, { M }> {
+    /// All of the elements of the matrix, in row-major order
+    fn flatten(&self) -> &[T] {
+        unsafe { std::slice::from_raw_parts(self.0.as_ptr() as *const T, N * M) }
+    }
+
+    /// All of the elements of the matrix, in row-major order
+    fn flatten_mut(&mut self) -> &mut [T] {
+        unsafe { std::slice::from_raw_parts_mut(self.0.as_mut_ptr() as *mut T, N * M) }
+    }
+
     /// Transposes the matrix,
     /// 
     /// This is some sugar for transpose
@@ -386,6 +454,8 @@ impl<T, const N: usize, const M: usize> Matrix<T, { N }, { M }> {
 
     /// Gets an element from the matrix
     /// 
+    /// # Safety
+    ///
     /// If the row or col is out of bounds, this causes UB on release mode, and panics on debug mode
     pub unsafe fn get_unchecked(&self, row: usize, col: usize) -> &T {
         self.check_bounds(row, col);
@@ -395,6 +465,8 @@ impl<T, const N: usize, const M: usize> Matrix<T, { N }, { M }> {
 
     /// Gets an element from the matrix
     /// 
+    /// # Safety
+    ///
     /// If the row or col is out of bounds, this causes UB on release mode, and panics on debug mode
     pub unsafe fn get_unchecked_mut(&mut self, row: usize, col: usize) -> &mut T {
         self.check_bounds(row, col);
@@ -411,7 +483,8 @@ impl<T, const N: usize, const M: usize> Matrix<T, { N }, { M }> {
     /// Example,
     /// 
     /// ```
-    /// let m = Matrix(
+    /// # use const_alg::Matrix;
+    /// let mut m = Matrix(
     ///     [[0, 1, 2],
     ///      [3, 4, 5]]
     /// );
@@ -433,19 +506,25 @@ impl<T, const N: usize, const M: usize> Matrix<T, { N }, { M }> {
 
         let mut pos = pos.into_iter();
 
-        if let Some((mut last, i)) = pos.next() {
-            let (row, col) = last;
+        // only the pointer to the elements is kept, so that the unique references that were
+        // already handed out aren't invalidated by reborrowing the whole matrix
+        let this = self.flatten_mut().as_mut_ptr();
+        let get = |(row, col): (usize, usize)| {
+            if row < N && col < M {
+                unsafe { Some(&mut *this.add(row * M + col)) }
+            } else {
+                None
+            }
+        };
 
-            let this: *mut Self = self;
-            output[i] = unsafe { (*this).get_mut(row, col) };
+        if let Some((mut last, i)) = pos.next() {
+            output[i] = get(last);
 
             for (pos, i) in pos {
                 if last != pos {
                     last = pos;
 
-                    let (row, col) = pos;
-
-                    output[i] = unsafe { (*this).get_mut(row, col) };
+                    output[i] = get(pos);
                 }
             }
         }
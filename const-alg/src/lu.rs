@@ -0,0 +1,224 @@
+use crate::{Matrix, SquareMatrix};
+use num::{Float, One, Zero};
+
+use std::cmp::Ordering;
+
+/// The LU decomposition of a square matrix, with partial pivoting
+///
+/// This represents `P * A = L * U`, where `P` is a permutation matrix,
+/// `L` is a lower triangular matrix with ones on the diagonal, and `U` is an upper triangular matrix
+///
+/// `L` and `U` are packed together into a single matrix, and `P` is stored as the row permutation,
+/// so once the decomposition is built it can be used to solve many systems cheaply
+#[derive(Debug, Clone, Copy)]
+pub struct Lu<T, const N: usize> {
+    lu: SquareMatrix<T, { N }>,
+    perm: [usize; N],
+    odd_swaps: bool,
+    /// The largest absolute value of the elements of the decomposed matrix
+    scale: T,
+}
+
+impl<T: Float, const N: usize> Matrix<T, { N }, { N }> {
+    /// Calculates the LU decomposition of the matrix
+    pub fn lu(&self) -> Lu<T, { N }> {
+        Lu::new(*self)
+    }
+}
+
+impl<T: Float, const N: usize> Lu<T, { N }> {
+    /// Decomposes the given matrix
+    ///
+    /// This never fails, if the matrix is singular then `U` will have a zero (or something very close to it)
+    /// on its diagonal
+    pub fn new(mut lu: SquareMatrix<T, { N }>) -> Self {
+        let scale = lu
+            .iter()
+            .flat_map(|row| row.iter())
+            .fold(T::zero(), |acc, x| acc.max(x.abs()));
+        let mut perm = [0; N];
+        let mut odd_swaps = false;
+
+        for (i, p) in perm.iter_mut().enumerate() {
+            *p = i;
+        }
+
+        for k in 0..N {
+            let p = (k..N)
+                .max_by(|&a, &b| {
+                    lu[(a, k)]
+                        .abs()
+                        .partial_cmp(&lu[(b, k)].abs())
+                        .unwrap_or(Ordering::Equal)
+                })
+                .unwrap_or(k);
+
+            if p != k {
+                lu.swap(p, k);
+                perm.swap(p, k);
+                odd_swaps = !odd_swaps;
+            }
+
+            let pivot = lu[(k, k)];
+
+            if pivot.is_zero() {
+                continue;
+            }
+
+            for i in k + 1..N {
+                let factor = lu[(i, k)] / pivot;
+
+                lu[(i, k)] = factor;
+
+                for j in k + 1..N {
+                    lu[(i, j)] = lu[(i, j)] - factor * lu[(k, j)];
+                }
+            }
+        }
+
+        Self {
+            lu,
+            perm,
+            odd_swaps,
+            scale,
+        }
+    }
+
+    /// Checks if the decomposed matrix is singular
+    ///
+    /// This is `is_singular_with(T::epsilon())`
+    pub fn is_singular(&self) -> bool {
+        self.is_singular_with(T::epsilon())
+    }
+
+    /// Checks if the decomposed matrix is singular, i.e. if `U` has a pivot whose absolute value is
+    /// not greater than `tol` times the largest absolute value of the elements of the decomposed matrix
+    ///
+    /// Rounding errors usually leave a tiny pivot instead of a zero, so the tolerance is relative
+    pub fn is_singular_with(&self, tol: T) -> bool {
+        (0..N).any(|i| {
+            let pivot = self.lu[(i, i)].abs();
+
+            pivot.is_nan() || pivot <= tol * self.scale
+        })
+    }
+
+    /// The determinant of the decomposed matrix
+    pub fn det(&self) -> T {
+        let det = (0..N).fold(T::one(), |acc, i| acc * self.lu[(i, i)]);
+
+        if self.odd_swaps {
+            -det
+        } else {
+            det
+        }
+    }
+
+    /// Solves `A * X = B` for `X`, where `A` is the decomposed matrix
+    ///
+    /// Each column of `b` is a separate right hand side.
+    /// If the decomposed matrix is singular (see `is_singular`), `None` is returned
+    pub fn solve<const K: usize>(&self, b: Matrix<T, { N }, { K }>) -> Option<Matrix<T, { N }, { K }>> {
+        self.solve_with(b, T::epsilon())
+    }
+
+    /// Solves `A * X = B` for `X`, where `A` is the decomposed matrix
+    ///
+    /// Each column of `b` is a separate right hand side.
+    /// If `is_singular_with(tol)` is true, `None` is returned
+    pub fn solve_with<const K: usize>(
+        &self,
+        b: Matrix<T, { N }, { K }>,
+        tol: T,
+    ) -> Option<Matrix<T, { N }, { K }>> {
+        if self.is_singular_with(tol) {
+            return None;
+        }
+
+        let lu = &self.lu;
+        let mut x = b;
+
+        for (row, &p) in x.iter_mut().zip(self.perm.iter()) {
+            *row = b.0[p];
+        }
+
+        // forward substitution with `L`
+        for i in 0..N {
+            for j in 0..i {
+                let (factor, x_j) = (lu[(i, j)], x.0[j]);
+
+                x.0[i]
+                    .iter_mut()
+                    .zip(x_j.iter())
+                    .for_each(|(x, &y)| *x = *x - factor * y);
+            }
+        }
+
+        // backward substitution with `U`
+        for i in (0..N).rev() {
+            for j in i + 1..N {
+                let (factor, x_j) = (lu[(i, j)], x.0[j]);
+
+                x.0[i]
+                    .iter_mut()
+                    .zip(x_j.iter())
+                    .for_each(|(x, &y)| *x = *x - factor * y);
+            }
+
+            let pivot = lu[(i, i)];
+
+            x.0[i].iter_mut().for_each(|x| *x = *x / pivot);
+        }
+
+        Some(x)
+    }
+
+    /// The inverse of the decomposed matrix, or `None` if it is singular (see `is_singular`)
+    pub fn inverse(&self) -> Option<SquareMatrix<T, { N }>> {
+        self.solve(SquareMatrix::one())
+    }
+
+    /// The inverse of the decomposed matrix, or `None` if `is_singular_with(tol)` is true
+    pub fn inverse_with(&self, tol: T) -> Option<SquareMatrix<T, { N }>> {
+        self.solve_with(SquareMatrix::one(), tol)
+    }
+
+    /// The lower triangular factor, with ones on the diagonal
+    pub fn l(&self) -> SquareMatrix<T, { N }> {
+        let mut l = self.lu;
+
+        for (i, row) in l.iter_mut().enumerate() {
+            row[i] = T::one();
+            row[i + 1..].iter_mut().for_each(T::set_zero);
+        }
+
+        l
+    }
+
+    /// The upper triangular factor
+    pub fn u(&self) -> SquareMatrix<T, { N }> {
+        let mut u = self.lu;
+
+        for (i, row) in u.iter_mut().enumerate() {
+            row[..i].iter_mut().for_each(T::set_zero);
+        }
+
+        u
+    }
+
+    /// The permutation matrix `P`
+    pub fn p(&self) -> SquareMatrix<T, { N }> {
+        let mut p = SquareMatrix::<T, { N }>::zero();
+
+        for (row, &col) in p.iter_mut().zip(self.perm.iter()) {
+            row[col] = T::one();
+        }
+
+        p
+    }
+
+    /// The row permutation, row `i` of `P * A` is row `permutation()[i]` of `A`
+    pub fn permutation(&self) -> [usize; N] {
+        self.perm
+    }
+}
@@ -1,8 +1,17 @@
-use crate::{collect_array, collect_mat, into_iter, ForEach, Matrix};
+use crate::{collect_array, collect_mat, into_iter, Matrix};
 
 /// Maps the matrix eagerly with the given function
 /// 
 /// This takes a function that accepts a elements of the matrix and produces a new matrix from the result of the map
+/// 
+/// If the function returns `()`, then the output is a `Matrix<(), N, M>`, which doesn't take up any space,
+/// but if you only need the side effects, `ForEach` says that more clearly
+/// 
+/// # Panic safety
+/// 
+/// If the function panics, the outputs it already returned are dropped along with the partially built matrix.
+/// When mapping a matrix by value, the elements it hasn't been called on yet are dropped too,
+/// mapping a reference leaves the matrix alone. See the [crate docs](crate#panic-safety)
 pub trait Map<F> {
     type Output;
 
@@ -15,7 +24,7 @@ where
 {
     type Output = Matrix<U, { N }, { M }>;
 
-    default fn map(self, mut f: F) -> Self::Output {
+    fn map(self, mut f: F) -> Self::Output {
         unsafe {
             collect_mat(into_iter(self.0).map(|row| unsafe {
                 let arr: [_; M] = collect_array(into_iter(row).map(&mut f));
@@ -26,26 +35,13 @@ where
     }
 }
 
-impl<F, T, const N: usize, const M: usize> Map<F> for Matrix<T, { N }, { M }>
-where
-    F: FnMut(T),
-{
-    default fn map(self, f: F) -> Self::Output {
-        self.for_each(f);
-
-        assert_eq!(std::mem::size_of::<Self::Output>(), 0);
-
-        unsafe { std::mem::uninitialized() }
-    }
-}
-
 impl<'a, F, T, U, const N: usize, const M: usize> Map<F> for &'a mut Matrix<T, { N }, { M }>
 where
     F: FnMut(&'a mut T) -> U,
 {
     type Output = Matrix<U, { N }, { M }>;
 
-    default fn map(self, mut f: F) -> Self::Output {
+    fn map(self, mut f: F) -> Self::Output {
         unsafe {
             collect_mat(self.0.iter_mut().map(|row| unsafe {
                 let arr: [_; M] = collect_array(row.iter_mut().map(&mut f));
@@ -62,7 +58,7 @@ where
 {
     type Output = Matrix<U, { N }, { M }>;
 
-    default fn map(self, mut f: F) -> Self::Output {
+    fn map(self, mut f: F) -> Self::Output {
         unsafe {
             collect_mat(self.0.iter().map(|row| unsafe {
                 let arr: [_; M] = collect_array(row.iter().map(&mut f));
@@ -72,29 +68,3 @@ where
         }
     }
 }
-
-impl<'a, T, F, const N: usize, const M: usize> Map<F> for &'a mut Matrix<T, { N }, { M }>
-where
-    F: FnMut(&'a mut T),
-{
-    default fn map(self, f: F) -> Self::Output {
-        self.for_each(f);
-
-        assert_eq!(std::mem::size_of::<Self::Output>(), 0);
-
-        unsafe { std::mem::uninitialized() }
-    }
-}
-
-impl<'a, 'b, T, F, const N: usize, const M: usize> Map<F> for &'a Matrix<T, { N }, { M }>
-where
-    F: FnMut(&'a T),
-{
-    default fn map(self, f: F) -> Self::Output {
-        self.for_each(f);
-
-        assert_eq!(std::mem::size_of::<Self::Output>(), 0);
-
-        unsafe { std::mem::uninitialized() }
-    }
-}
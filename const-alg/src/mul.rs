@@ -1,7 +1,7 @@
 use super::*;
 
-impl<'a, 'b, T, const N: usize, const M: usize, const O: usize> Mul<&'b Matrix<T, { M }, { O }>>
-    for &'a Matrix<T, { N }, { M }>
+impl<'b, T, const N: usize, const M: usize, const O: usize> Mul<&'b Matrix<T, { M }, { O }>>
+    for &Matrix<T, { N }, { M }>
 where
     T: Add<Output = T> + Mul<Output = T> + Zero + Clone,
 {
@@ -17,8 +17,8 @@ where
     }
 }
 
-impl<'a, 'b, T, const N: usize, const M: usize, const O: usize> Mul<&'b mut Matrix<T, { M }, { O }>>
-    for &'a Matrix<T, { N }, { M }>
+impl<'b, T, const N: usize, const M: usize, const O: usize> Mul<&'b mut Matrix<T, { M }, { O }>>
+    for &Matrix<T, { N }, { M }>
 where
     T: Add<Output = T> + Mul<Output = T> + Zero + Clone,
 {
@@ -32,8 +32,8 @@ where
     }
 }
 
-impl<'a, 'b, T, const N: usize, const M: usize, const O: usize> Mul<Matrix<T, { M }, { O }>>
-    for &'a Matrix<T, { N }, { M }>
+impl<T, const N: usize, const M: usize, const O: usize> Mul<Matrix<T, { M }, { O }>>
+    for &Matrix<T, { N }, { M }>
 where
     T: Add<Output = T> + Mul<Output = T> + Zero + Clone,
 {
@@ -47,8 +47,8 @@ where
     }
 }
 
-impl<'a, 'b, T, const N: usize, const M: usize, const O: usize> Mul<&'b Matrix<T, { M }, { O }>>
-    for &'a mut Matrix<T, { N }, { M }>
+impl<'b, T, const N: usize, const M: usize, const O: usize> Mul<&'b Matrix<T, { M }, { O }>>
+    for &mut Matrix<T, { N }, { M }>
 where
     T: Add<Output = T> + Mul<Output = T> + Zero + Clone,
 {
@@ -62,8 +62,8 @@ where
     }
 }
 
-impl<'a, 'b, T, const N: usize, const M: usize, const O: usize> Mul<&'b mut Matrix<T, { M }, { O }>>
-    for &'a mut Matrix<T, { N }, { M }>
+impl<'b, T, const N: usize, const M: usize, const O: usize> Mul<&'b mut Matrix<T, { M }, { O }>>
+    for &mut Matrix<T, { N }, { M }>
 where
     T: Add<Output = T> + Mul<Output = T> + Zero + Clone,
 {
@@ -77,8 +77,8 @@ where
     }
 }
 
-impl<'a, 'b, T, const N: usize, const M: usize, const O: usize> Mul<Matrix<T, { M }, { O }>>
-    for &'a mut Matrix<T, { N }, { M }>
+impl<T, const N: usize, const M: usize, const O: usize> Mul<Matrix<T, { M }, { O }>>
+    for &mut Matrix<T, { N }, { M }>
 where
     T: Add<Output = T> + Mul<Output = T> + Zero + Clone,
 {
@@ -92,7 +92,7 @@ where
     }
 }
 
-impl<'a, 'b, T, const N: usize, const M: usize, const O: usize> Mul<&'b Matrix<T, { M }, { O }>>
+impl<'b, T, const N: usize, const M: usize, const O: usize> Mul<&'b Matrix<T, { M }, { O }>>
     for Matrix<T, { N }, { M }>
 where
     T: Add<Output = T> + Mul<Output = T> + Zero + Clone,
@@ -107,7 +107,7 @@ where
     }
 }
 
-impl<'a, 'b, T, const N: usize, const M: usize, const O: usize> Mul<&'b mut Matrix<T, { M }, { O }>>
+impl<'b, T, const N: usize, const M: usize, const O: usize> Mul<&'b mut Matrix<T, { M }, { O }>>
     for Matrix<T, { N }, { M }>
 where
     T: Add<Output = T> + Mul<Output = T> + Zero + Clone,
@@ -122,7 +122,7 @@ where
     }
 }
 
-impl<'a, 'b, T, const N: usize, const M: usize, const O: usize> Mul<Matrix<T, { M }, { O }>>
+impl<T, const N: usize, const M: usize, const O: usize> Mul<Matrix<T, { M }, { O }>>
     for Matrix<T, { N }, { M }>
 where
     T: Add<Output = T> + Mul<Output = T> + Zero + Clone,
@@ -0,0 +1,233 @@
+use crate::{collect_mat, to_array, Matrix};
+
+use array_vec::ArrayVec;
+
+use std::fmt;
+use std::str::FromStr;
+
+/// The error returned when parsing a matrix fails
+///
+/// Rows and columns are counted from zero, like when indexing a matrix
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseMatrixError<E> {
+    /// The brackets are unbalanced, or there is something outside of them
+    ///
+    /// `offset` is the byte offset into the text of the misplaced bracket,
+    /// or of where the missing one should have been
+    Brackets { offset: usize },
+    /// There are `found` rows, but the matrix has `expected` rows
+    Rows { expected: usize, found: usize },
+    /// The row `row` has `found` elements, but the matrix has `expected` columns
+    Cols {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// The element at `(row, col)` couldn't be parsed
+    Element { row: usize, col: usize, error: E },
+}
+
+impl<E: fmt::Display> fmt::Display for ParseMatrixError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Brackets { offset } => write!(
+                f,
+                "the brackets are unbalanced or misplaced at byte {}",
+                offset
+            ),
+            Self::Rows { expected, found } => {
+                write!(f, "expected {} rows, but found {}", expected, found)
+            }
+            Self::Cols {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "expected {} columns, but found {} in row {}",
+                expected, found, row
+            ),
+            Self::Element { row, col, error } => {
+                write!(f, "invalid element at ({}, {}): {}", row, col, error)
+            }
+        }
+    }
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for ParseMatrixError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Element { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Splits a row into its elements
+///
+/// If there are any commas, the elements are separated by commas (so empty elements are kept),
+/// otherwise they are separated by whitespace
+fn elements(row: &str) -> impl Iterator<Item = &str> {
+    let commas = row.contains(',');
+
+    row.split(move |c: char| if commas { c == ',' } else { c.is_whitespace() })
+        .map(str::trim)
+        .filter(move |x| commas || !x.is_empty())
+}
+
+/// The byte offset of `rest` in `text`, which it must be a part of
+fn offset(text: &str, rest: &str) -> usize {
+    rest.as_ptr() as usize - text.as_ptr() as usize
+}
+
+/// The rows of the text, or the offset of a misplaced bracket if there is one
+enum Rows<'a> {
+    /// `[[1, 2], [3, 4]]`, where `rest` is the text after the rows so far
+    ///
+    /// The commas between rows are optional, like how NumPy prints arrays
+    Nested {
+        text: &'a str,
+        rest: &'a str,
+        first: bool,
+    },
+    /// `[1 2; 3 4]` or `1 2\n3 4`, with the outer brackets already removed
+    Flat(std::str::Split<'a, [char; 2]>),
+}
+
+impl<'a> Iterator for Rows<'a> {
+    type Item = Result<&'a str, usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Nested { text, rest, first } => {
+                let mut s = rest.trim_start();
+
+                if !*first {
+                    s = s.strip_prefix(',').unwrap_or(s).trim_start();
+                }
+
+                *first = false;
+
+                if s.is_empty() {
+                    return None;
+                }
+
+                // stop after an error, by leaving nothing behind
+                let start = offset(text, s);
+                *rest = "";
+
+                let end = match s.find(']') {
+                    Some(end) if s.starts_with('[') => end,
+                    _ => return Some(Err(start)),
+                };
+
+                let row = &s[1..end];
+
+                if let Some(i) = row.find('[') {
+                    return Some(Err(start + 1 + i));
+                }
+
+                *rest = &s[end + 1..];
+
+                Some(Ok(row))
+            }
+            Self::Flat(rows) => rows.find(|row| !row.trim().is_empty()).map(Ok),
+        }
+    }
+}
+
+/// Splits the text into rows, checking the outer brackets
+fn rows(text: &str) -> Result<Rows<'_>, usize> {
+    let s = text.trim();
+
+    let rows = match s.strip_prefix('[') {
+        Some(inner) => {
+            let inner = inner
+                .strip_suffix(']')
+                .ok_or_else(|| offset(text, s) + s.len())?;
+
+            if inner.trim_start().starts_with('[') {
+                // empty rows have their own brackets here, so they are kept
+                return Ok(Rows::Nested {
+                    text,
+                    rest: inner,
+                    first: true,
+                });
+            }
+
+            inner
+        }
+        None => s,
+    };
+
+    match rows.find(['[', ']']) {
+        Some(i) => Err(offset(text, rows) + i),
+        None => Ok(Rows::Flat(rows.split([';', '\n']))),
+    }
+}
+
+/// Parses a matrix from text
+///
+/// These all parse to the same matrix,
+///
+/// * `[1 2 3; 4 5 6]`, where rows are separated by semicolons or new lines
+/// * `[[1, 2, 3], [4, 5, 6]]`, where each row has its own brackets
+/// * `1,2,3\n4,5,6` or `1 2 3\n4 5 6`, without any brackets
+///
+/// The elements in a row are separated by commas if there are any, otherwise by whitespace.
+/// Blank lines are ignored
+///
+/// ```rust
+/// # use const_alg::Matrix;
+/// let m: Matrix<i32, 2, 3> = "[1 2 3; 4 5 6]".parse().unwrap();
+///
+/// assert_eq!(m, Matrix([[1, 2, 3], [4, 5, 6]]));
+/// ```
+impl<T: FromStr, const N: usize, const M: usize> FromStr for Matrix<T, { N }, { M }> {
+    type Err = ParseMatrixError<T::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let brackets = |offset| ParseMatrixError::Brackets { offset };
+
+        // the text is split twice, first to check the brackets and count the rows, then to parse them
+        let mut found = 0;
+
+        for row in rows(s).map_err(brackets)? {
+            row.map_err(brackets)?;
+            found += 1;
+        }
+
+        if found != N {
+            return Err(ParseMatrixError::Rows { expected: N, found });
+        }
+
+        let rows = rows(s)
+            .map_err(brackets)?
+            .enumerate()
+            .map(|(row, text)| {
+                let text = text.map_err(brackets)?;
+                let found = elements(text).count();
+
+                if found != M {
+                    return Err(ParseMatrixError::Cols {
+                        row,
+                        expected: M,
+                        found,
+                    });
+                }
+
+                let elements = elements(text)
+                    .enumerate()
+                    .map(|(col, x)| {
+                        x.parse()
+                            .map_err(|error| ParseMatrixError::Element { row, col, error })
+                    })
+                    .collect::<Result<ArrayVec<T, { M }>, _>>()?;
+
+                Ok(unsafe { to_array(elements) })
+            })
+            .collect::<Result<ArrayVec<[T; M], { N }>, _>>()?;
+
+        Ok(unsafe { collect_mat(rows) })
+    }
+}
@@ -0,0 +1,175 @@
+use crate::{collect_array, collect_mat, Matrix};
+use num::{Float, One};
+
+/// The QR decomposition of an `N x M` matrix, where `N >= M`
+///
+/// This represents `A = Q * R`, where `Q` is an `N x N` orthogonal matrix
+/// and `R` is an `N x M` upper triangular matrix
+///
+/// The decomposition is computed with Householder reflections
+#[derive(Debug, Clone, Copy)]
+pub struct Qr<T, const N: usize, const M: usize> {
+    q: Matrix<T, { N }, { N }>,
+    r: Matrix<T, { N }, { M }>,
+    /// The largest absolute value of the elements of the decomposed matrix
+    scale: T,
+}
+
+impl<T: Float, const N: usize, const M: usize> Matrix<T, { N }, { M }> {
+    /// Calculates the QR decomposition of the matrix
+    ///
+    /// Panics if there are fewer rows than columns
+    pub fn qr(&self) -> Qr<T, { N }, { M }> {
+        Qr::new(*self)
+    }
+}
+
+impl<T: Float, const N: usize, const M: usize> Qr<T, { N }, { M }> {
+    /// Decomposes the given matrix
+    ///
+    /// Panics if there are fewer rows than columns
+    pub fn new(mut r: Matrix<T, { N }, { M }>) -> Self {
+        assert!(N >= M, "the QR decomposition needs at least as many rows as columns");
+
+        let scale = r
+            .iter()
+            .flat_map(|row| row.iter())
+            .fold(T::zero(), |acc, x| acc.max(x.abs()));
+
+        let mut q = Matrix::<T, { N }, { N }>::one();
+        let two = T::one() + T::one();
+
+        for k in 0..M {
+            let mut v = [T::zero(); N];
+
+            for (v, &x) in v.iter_mut().zip(r.cols().nth(k).unwrap()).skip(k) {
+                *v = x;
+            }
+
+            let norm = v.iter().fold(T::zero(), |acc, &x| acc + x * x).sqrt();
+
+            let alpha = if v[k] < T::zero() { norm } else { -norm };
+
+            v[k] = v[k] - alpha;
+
+            let v_norm = v.iter().fold(T::zero(), |acc, &x| acc + x * x).sqrt();
+
+            if v_norm.is_zero() {
+                continue;
+            }
+
+            v.iter_mut().for_each(|x| *x = *x / v_norm);
+
+            // R = H * R, only the columns from `k` onwards are affected
+            for j in k..M {
+                let dot = r
+                    .cols()
+                    .nth(j)
+                    .unwrap()
+                    .zip(v.iter())
+                    .fold(T::zero(), |acc, (&x, &y)| acc + x * y);
+
+                for (row, &v) in r.iter_mut().zip(v.iter()).skip(k) {
+                    row[j] = row[j] - two * dot * v;
+                }
+            }
+
+            // Q = Q * H
+            for row in q.iter_mut() {
+                let dot = row
+                    .iter()
+                    .zip(v.iter())
+                    .fold(T::zero(), |acc, (&x, &y)| acc + x * y);
+
+                row.iter_mut()
+                    .zip(v.iter())
+                    .for_each(|(x, &v)| *x = *x - two * dot * v);
+            }
+        }
+
+        Self { q, r, scale }
+    }
+
+    /// The orthogonal factor, `N x N`
+    pub fn q(&self) -> Matrix<T, { N }, { N }> {
+        self.q
+    }
+
+    /// The upper triangular factor, `N x M`
+    pub fn r(&self) -> Matrix<T, { N }, { M }> {
+        self.r
+    }
+
+    /// The first `M` columns of `Q`, for the thin QR decomposition `A = Q1 * R1`
+    pub fn thin_q(&self) -> Matrix<T, { N }, { M }> {
+        unsafe { collect_mat(self.q.rows().map(|row| collect_array(row.cloned()))) }
+    }
+
+    /// The first `M` rows of `R`, for the thin QR decomposition `A = Q1 * R1`
+    pub fn thin_r(&self) -> Matrix<T, { M }, { M }> {
+        unsafe { collect_mat(self.r.rows().map(|row| collect_array(row.cloned()))) }
+    }
+
+    /// Checks if the decomposed matrix has linearly dependent columns
+    ///
+    /// This is `is_rank_deficient_with(T::epsilon())`
+    pub fn is_rank_deficient(&self) -> bool {
+        self.is_rank_deficient_with(T::epsilon())
+    }
+
+    /// Checks if the decomposed matrix has linearly dependent columns, i.e. if `R` has a diagonal element whose
+    /// absolute value is not greater than `tol` times the largest absolute value of the elements of the decomposed matrix
+    ///
+    /// Rounding errors usually leave a tiny diagonal element instead of a zero, so the tolerance is relative
+    pub fn is_rank_deficient_with(&self, tol: T) -> bool {
+        (0..M).any(|i| {
+            let diag = self.r[(i, i)].abs();
+
+            diag.is_nan() || diag <= tol * self.scale
+        })
+    }
+
+    /// Finds the least squares solution to `A * X = B`,
+    /// i.e. the `X` that minimizes the euclidean norm of each column of `A * X - B`
+    ///
+    /// Each column of `b` is a separate right hand side.
+    /// If the decomposed matrix is rank deficient (see `is_rank_deficient`), `None` is returned
+    pub fn solve<const K: usize>(&self, b: Matrix<T, { N }, { K }>) -> Option<Matrix<T, { M }, { K }>> {
+        self.solve_with(b, T::epsilon())
+    }
+
+    /// Finds the least squares solution to `A * X = B`, like `solve`
+    ///
+    /// If `is_rank_deficient_with(tol)` is true, `None` is returned
+    pub fn solve_with<const K: usize>(
+        &self,
+        b: Matrix<T, { N }, { K }>,
+        tol: T,
+    ) -> Option<Matrix<T, { M }, { K }>> {
+        if self.is_rank_deficient_with(tol) {
+            return None;
+        }
+
+        let qt_b = self.q.transpose() * b;
+        let mut x: Matrix<T, { M }, { K }> =
+            unsafe { collect_mat(qt_b.rows().map(|row| collect_array(row.cloned()))) };
+
+        // backward substitution with `R1`
+        for i in (0..M).rev() {
+            for j in i + 1..M {
+                let (factor, x_j) = (self.r[(i, j)], x.0[j]);
+
+                x.0[i]
+                    .iter_mut()
+                    .zip(x_j.iter())
+                    .for_each(|(x, &y)| *x = *x - factor * y);
+            }
+
+            let pivot = self.r[(i, i)];
+
+            x.0[i].iter_mut().for_each(|x| *x = *x / pivot);
+        }
+
+        Some(x)
+    }
+}
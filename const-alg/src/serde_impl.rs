@@ -0,0 +1,74 @@
+use crate::{collect_array, into_iter, to_array, Matrix};
+use array_vec::ArrayVec;
+
+use serde::de::{Deserialize, Deserializer, Error, IgnoredAny, SeqAccess, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use std::fmt;
+use std::marker::PhantomData;
+
+/// Serializes the matrix as a sequence of rows, where each row is a sequence of elements
+impl<T: Serialize, const N: usize, const M: usize> Serialize for Matrix<T, { N }, { M }> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter().map(|row| row as &[T]))
+    }
+}
+
+/// Deserializes a sequence of exactly `N` rows, where each row is a sequence of exactly `M` elements
+impl<'de, T: Deserialize<'de>, const N: usize, const M: usize> Deserialize<'de>
+    for Matrix<T, { N }, { M }>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let rows = deserializer.deserialize_seq(ExactVisitor::<Row<T, { M }>, { N }>(PhantomData))?;
+
+        Ok(Matrix(unsafe { collect_array(into_iter(rows).map(|Row(row)| row)) }))
+    }
+}
+
+/// A single row of a matrix
+struct Row<T, const M: usize>([T; M]);
+
+impl<'de, T: Deserialize<'de>, const M: usize> Deserialize<'de> for Row<T, { M }> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer
+            .deserialize_seq(ExactVisitor::<T, { M }>(PhantomData))
+            .map(Row)
+    }
+}
+
+/// Visits a sequence of exactly `N` elements
+struct ExactVisitor<T, const N: usize>(PhantomData<T>);
+
+impl<'de, T: Deserialize<'de>, const N: usize> Visitor<'de> for ExactVisitor<T, { N }> {
+    type Value = [T; N];
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a sequence of {} elements", N)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut arr = ArrayVec::<T, { N }>::default();
+
+        for i in 0..N {
+            match seq.next_element()? {
+                Some(value) => {
+                    let _ = arr.push(value);
+                }
+                None => return Err(A::Error::invalid_length(i, &self)),
+            }
+        }
+
+        // count the rest so the error says how long the sequence really is
+        let mut len = N;
+
+        while seq.next_element::<IgnoredAny>()?.is_some() {
+            len += 1;
+        }
+
+        if len != N {
+            return Err(A::Error::invalid_length(len, &self));
+        }
+
+        Ok(unsafe { to_array(arr) })
+    }
+}
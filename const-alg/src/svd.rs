@@ -0,0 +1,237 @@
+use crate::{Matrix, NoConvergence, SquareMatrix};
+use num::{Float, One, Zero};
+
+use std::cmp::Ordering;
+
+/// The singular value decomposition of an `N x M` matrix
+///
+/// This represents `A = U * S * V^T`, where `S` is a diagonal matrix of the singular values,
+/// `U` is an `N x M` matrix with orthonormal columns and `V` is an `M x M` orthogonal matrix
+///
+/// There are always `M` singular values, sorted from largest to smallest.
+/// If `N < M`, then the last `M - N` singular values are zero (up to rounding),
+/// and the corrosponding columns of `U` are meaningless
+///
+/// The decomposition is computed with one-sided Jacobi rotations,
+/// if they don't converge within 64 sweeps then `NoConvergence` is returned instead
+#[derive(Debug, Clone, Copy)]
+pub struct Svd<T, const N: usize, const M: usize> {
+    u: Matrix<T, { N }, { M }>,
+    values: [T; M],
+    v_t: SquareMatrix<T, { M }>,
+}
+
+impl<T: Float, const N: usize, const M: usize> Matrix<T, { N }, { M }> {
+    /// Calculates the singular value decomposition of the matrix
+    pub fn svd(&self) -> Result<Svd<T, { N }, { M }>, NoConvergence> {
+        Svd::new(*self)
+    }
+
+    /// Calculates the Moore-Penrose pseudo-inverse of the matrix,
+    /// singular values that are not greater than `tol` are treated as zero
+    pub fn pseudo_inverse(&self, tol: T) -> Result<Matrix<T, { M }, { N }>, NoConvergence> {
+        Ok(self.svd()?.pseudo_inverse(tol))
+    }
+
+    /// The number of singular values that are greater than `tol`
+    pub fn rank(&self, tol: T) -> Result<usize, NoConvergence> {
+        Ok(self.svd()?.rank(tol))
+    }
+
+    /// The ratio of the largest singular value to the smallest one
+    pub fn condition_number(&self) -> Result<T, NoConvergence> {
+        Ok(self.svd()?.condition_number())
+    }
+
+    /// The operator 2-norm, i.e. the largest singular value
+    pub fn norm2(&self) -> Result<T, NoConvergence> {
+        Ok(self.svd()?.norm2())
+    }
+}
+
+impl<T: Float, const N: usize, const M: usize> Svd<T, { N }, { M }> {
+    const MAX_SWEEPS: usize = 64;
+
+    /// Decomposes the given matrix
+    ///
+    /// If the rotations don't converge within `64` sweeps, `NoConvergence` is returned
+    pub fn new(mut u: Matrix<T, { N }, { M }>) -> Result<Self, NoConvergence> {
+        let mut v = SquareMatrix::<T, { M }>::one();
+        let two = T::one() + T::one();
+        let mut converged = false;
+
+        // the matrix is scaled so its largest element is one, so the sums of squares below can't
+        // overflow or underflow, and the singular values are scaled back at the end
+        let scale = u
+            .iter()
+            .flat_map(|row| row.iter())
+            .fold(T::zero(), |acc, x| acc.max(x.abs()));
+        let scale = if scale.is_zero() { T::one() } else { scale };
+
+        u.iter_mut()
+            .flat_map(|row| row.iter_mut())
+            .for_each(|x| *x = *x / scale);
+
+        // a column whose norm is this small compared to the whole matrix is numerically zero,
+        // and rotating it against another column only shuffles rounding errors around
+        let negligible = T::epsilon() * T::epsilon() * u.norm_squared();
+
+        for _ in 0..Self::MAX_SWEEPS {
+            let mut rotated = false;
+
+            for p in 0..M {
+                for q in p + 1..M {
+                    let (mut alpha, mut beta, mut gamma) = (T::zero(), T::zero(), T::zero());
+
+                    for row in u.iter() {
+                        alpha = alpha + row[p] * row[p];
+                        beta = beta + row[q] * row[q];
+                        gamma = gamma + row[p] * row[q];
+                    }
+
+                    if gamma.abs() <= T::epsilon() * (alpha * beta).sqrt()
+                        || alpha.min(beta) <= negligible
+                    {
+                        continue;
+                    }
+
+                    rotated = true;
+
+                    let zeta = (beta - alpha) / (two * gamma);
+                    let t = zeta.signum() / (zeta.abs() + zeta.hypot(T::one()));
+                    let c = T::one() / t.hypot(T::one());
+                    let s = t * c;
+
+                    for row in u.iter_mut() {
+                        let (x, y) = (row[p], row[q]);
+
+                        row[p] = c * x - s * y;
+                        row[q] = s * x + c * y;
+                    }
+
+                    for row in v.iter_mut() {
+                        let (x, y) = (row[p], row[q]);
+
+                        row[p] = c * x - s * y;
+                        row[q] = s * x + c * y;
+                    }
+                }
+            }
+
+            if !rotated {
+                converged = true;
+                break;
+            }
+        }
+
+        if !converged {
+            return Err(NoConvergence);
+        }
+
+        let mut norms = [T::zero(); M];
+
+        for (j, norm) in norms.iter_mut().enumerate() {
+            *norm = u.cols().nth(j).unwrap().fold(T::zero(), |acc, &x| acc + x * x).sqrt();
+        }
+
+        let mut order = [0; M];
+
+        for (i, o) in order.iter_mut().enumerate() {
+            *o = i;
+        }
+
+        order.sort_by(|&i, &j| norms[j].partial_cmp(&norms[i]).unwrap_or(Ordering::Equal));
+
+        let mut values = [T::zero(); M];
+        let mut sorted_u = Matrix::<T, { N }, { M }>::zero();
+        let mut v_t = SquareMatrix::<T, { M }>::zero();
+
+        for (i, &o) in order.iter().enumerate() {
+            let norm = norms[o];
+
+            values[i] = norm * scale;
+
+            if !norm.is_zero() {
+                for (row, u_row) in sorted_u.iter_mut().zip(u.iter()) {
+                    row[i] = u_row[o] / norm;
+                }
+            }
+
+            for (x, v_row) in v_t.0[i].iter_mut().zip(v.iter()) {
+                *x = v_row[o];
+            }
+        }
+
+        Ok(Self {
+            u: sorted_u,
+            values,
+            v_t,
+        })
+    }
+
+    /// The left singular vectors, as columns
+    pub fn u(&self) -> Matrix<T, { N }, { M }> {
+        self.u
+    }
+
+    /// The singular values, from largest to smallest
+    pub fn singular_values(&self) -> [T; M] {
+        self.values
+    }
+
+    /// The transpose of the right singular vectors, so the vectors are the rows
+    pub fn v_t(&self) -> SquareMatrix<T, { M }> {
+        self.v_t
+    }
+
+    /// The number of singular values that are greater than `tol`
+    pub fn rank(&self, tol: T) -> usize {
+        self.values.iter().filter(|&&x| x > tol).count()
+    }
+
+    /// The ratio of the largest singular value to the smallest one
+    ///
+    /// Only the first `min(N, M)` singular values are considered,
+    /// if the smallest one is zero then this is infinite
+    pub fn condition_number(&self) -> T {
+        match N.min(M) {
+            0 => T::one(),
+            k => self.values[0] / self.values[k - 1],
+        }
+    }
+
+    /// The operator 2-norm of the decomposed matrix, i.e. the largest singular value
+    pub fn norm2(&self) -> T {
+        self.values.first().cloned().unwrap_or_else(T::zero)
+    }
+
+    /// The Moore-Penrose pseudo-inverse of the decomposed matrix,
+    /// singular values that are not greater than `tol` are treated as zero
+    pub fn pseudo_inverse(&self, tol: T) -> Matrix<T, { M }, { N }> {
+        let mut pinv = Matrix::<T, { M }, { N }>::zero();
+
+        for (k, &value) in self.values.iter().enumerate() {
+            if value <= tol {
+                continue;
+            }
+
+            for (i, row) in pinv.iter_mut().enumerate() {
+                let v = self.v_t[(k, i)] / value;
+
+                for (x, u_row) in row.iter_mut().zip(self.u.iter()) {
+                    *x = *x + v * u_row[k];
+                }
+            }
+        }
+
+        pinv
+    }
+
+    /// Finds the minimum norm least squares solution to `A * X = B`,
+    /// singular values that are not greater than `tol` are treated as zero
+    ///
+    /// Each column of `b` is a separate right hand side
+    pub fn solve<const K: usize>(&self, b: Matrix<T, { N }, { K }>, tol: T) -> Matrix<T, { M }, { K }> {
+        self.pseudo_inverse(tol) * b
+    }
+}
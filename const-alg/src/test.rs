@@ -1,4 +1,6 @@
-use crate::{Matrix, One, SquareMatrix, Zero};
+#![allow(clippy::iter_nth_zero)]
+
+use crate::{Map, Matrix, One, SquareMatrix, Zero, ZipWith};
 
 #[test]
 fn test_mul() {
@@ -171,3 +173,1160 @@ fn test_get_all_mut() {
 
     assert_eq!(all, [Some(&mut 0), Some(&mut 2), None, None]);
 }
+
+#[test]
+fn test_det() {
+    let m = Matrix([[2i64, -3, 1, 5], [4, 0, -2, 1], [-1, 7, 3, 2], [6, 1, -4, 0]]);
+
+    assert_eq!(m.det(), 51);
+
+    let m = Matrix([[1i64, 2, 3], [4, 5, 6], [7, 8, 9]]);
+
+    assert_eq!(m.det(), 0);
+
+    let m = Matrix([[0i32, 1], [1, 0]]);
+
+    assert_eq!(m.det(), -1);
+
+    assert_eq!(Matrix::<i64, 0, 0>([]).det(), 1);
+
+    let m = Matrix([[2.0, -3.0, 1.0], [2.0, 0.0, -1.0], [1.0, 4.0, 5.0]]);
+
+    assert!((m.det() - 49.0f64).abs() < 1e-12);
+
+    let m = Matrix([[1.0f32, 2.0], [2.0, 4.0]]);
+
+    assert_eq!(m.det(), 0.0);
+}
+
+fn approx_eq<const N: usize, const M: usize>(
+    a: &Matrix<f64, { N }, { M }>,
+    b: &Matrix<f64, { N }, { M }>,
+) -> bool {
+    a.iter()
+        .flat_map(|row| row.iter())
+        .zip(b.iter().flat_map(|row| row.iter()))
+        .all(|(a, b)| (a - b).abs() < 1e-9)
+}
+
+#[test]
+fn test_inverse() {
+    fn check<const N: usize>(m: SquareMatrix<f64, N>) {
+        let inv = m.try_inverse().unwrap();
+
+        assert!(approx_eq(&(m * inv), &One::one()));
+        assert!(approx_eq(&(inv * m), &One::one()));
+
+        let mut n = m;
+
+        assert!(n.invert());
+        assert!(approx_eq(&n, &inv));
+    }
+
+    check(Matrix([[4.0, 7.0], [2.0, 6.0]]));
+    check(Matrix([[2.0, -3.0, 1.0], [2.0, 0.0, -1.0], [1.0, 4.0, 5.0]]));
+    check(Matrix([
+        [2.0, -3.0, 1.0, 5.0],
+        [4.0, 0.0, -2.0, 1.0],
+        [-1.0, 7.0, 3.0, 2.0],
+        [6.0, 1.0, -4.0, 0.0],
+    ]));
+    check(Matrix([
+        [0.0, 2.0, 1.0, 3.0, 1.0],
+        [1.0, 0.0, 4.0, 1.0, 2.0],
+        [3.0, 1.0, 0.0, 2.0, 5.0],
+        [2.0, 5.0, 1.0, 0.0, 1.0],
+        [1.0, 1.0, 2.0, 4.0, 0.0],
+    ]));
+    check(Matrix([[0.5]]));
+
+    let mut singular = Matrix([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+
+    assert_eq!(singular.try_inverse(), None);
+    assert!(!singular.invert());
+    assert_eq!(singular, Matrix([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]));
+
+    let mut singular = SquareMatrix::<f64, 5>::one();
+    singular[(3, 3)] = 0.0;
+
+    assert_eq!(singular.try_inverse(), None);
+
+    let nearly_singular = Matrix([[1.0, 1.0], [1.0, 1.0 + 1e-6]]);
+
+    assert!(nearly_singular.try_inverse().is_some());
+    assert_eq!(nearly_singular.try_inverse_with(1e-3), None);
+
+    // whether a matrix is singular doesn't depend on its scale
+    check(SquareMatrix::<f64, 3>::one() * 1e-6);
+    check(SquareMatrix::<f64, 5>::one() * 1e-4);
+    check(SquareMatrix::<f64, 4>::one() * 1e20);
+    check(Matrix([[1e-3, 0.0], [0.0, 2e-4]]));
+
+    let mut small = SquareMatrix::<f32, 4>::one() * 0.01;
+
+    assert!(small.invert());
+    assert!((small[(2, 2)] - 100.0).abs() < 1e-3);
+    assert_eq!((singular * 1e-10).try_inverse(), None);
+    assert_eq!(Matrix([[0.0; 3]; 3]).try_inverse(), None);
+}
+
+#[test]
+fn test_lu() {
+    let m = Matrix([[0.0, 2.0, 1.0], [1.0, 1.0, 0.0], [4.0, 2.0, 3.0]]);
+
+    let lu = m.lu();
+
+    assert!(approx_eq(&(lu.p() * m), &(lu.l() * lu.u())));
+    assert_eq!(lu.permutation(), [2, 0, 1]);
+    assert!((lu.det() - m.det()).abs() < 1e-12);
+    assert!((lu.det() + 8.0).abs() < 1e-12);
+
+    for i in 0..3 {
+        assert_eq!(lu.l()[(i, i)], 1.0);
+
+        for j in i + 1..3 {
+            assert_eq!(lu.l()[(i, j)], 0.0);
+            assert_eq!(lu.u()[(j, i)], 0.0);
+        }
+    }
+
+    let b = Matrix([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+    let x = lu.solve(b).unwrap();
+
+    assert!(approx_eq(&(m * x), &b));
+    assert!(approx_eq(&lu.inverse().unwrap(), &m.try_inverse().unwrap()));
+
+    let singular = Matrix([[1.0, 2.0], [2.0, 4.0]]).lu();
+
+    assert!(singular.is_singular());
+    assert_eq!(singular.det(), 0.0);
+    assert_eq!(singular.solve(Matrix([[1.0], [1.0]])), None);
+    assert_eq!(singular.inverse(), None);
+
+    // rounding leaves a tiny pivot instead of a zero here
+    let rank_deficient = Matrix([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]).lu();
+
+    assert!(rank_deficient.is_singular());
+    assert_eq!(rank_deficient.solve(Matrix([[1.0], [2.0], [3.0]])), None);
+    assert_eq!(rank_deficient.inverse(), None);
+    assert!(!rank_deficient.is_singular_with(0.0));
+    assert!(rank_deficient.inverse_with(0.0).is_some());
+
+    let nearly_singular = Matrix([[1.0, 1.0], [1.0, 1.0 + 1e-6]]).lu();
+
+    assert!(nearly_singular.solve(Matrix([[1.0], [1.0]])).is_some());
+    assert_eq!(nearly_singular.solve_with(Matrix([[1.0], [1.0]]), 1e-3), None);
+    assert!(!(SquareMatrix::<f64, 3>::one() * 1e-9).lu().is_singular());
+}
+
+#[test]
+fn test_qr() {
+    let m = Matrix([
+        [1.0, 2.0, 0.0],
+        [3.0, -1.0, 2.0],
+        [0.0, 4.0, 1.0],
+        [2.0, 1.0, -3.0],
+    ]);
+
+    let qr = m.qr();
+    let (q, r) = (qr.q(), qr.r());
+
+    assert!(approx_eq(&(q * r), &m));
+    assert!(approx_eq(&(q.transpose() * q), &One::one()));
+    assert!(approx_eq(&(qr.thin_q() * qr.thin_r()), &m));
+
+    for i in 0..4 {
+        for j in 0..i.min(3) {
+            assert!(r[(i, j)].abs() < 1e-12);
+        }
+    }
+
+    // fit `y = a + b * x` through points on a line
+    let a = Matrix([[1.0, 0.0], [1.0, 1.0], [1.0, 2.0], [1.0, 3.0]]);
+    let y = Matrix([[1.0], [3.0], [5.0], [7.0]]);
+
+    assert!(approx_eq(&a.qr().solve(y).unwrap(), &Matrix([[1.0], [2.0]])));
+
+    // compare against the normal equations
+    let y = Matrix([[0.5, 1.0], [2.5, 0.0], [5.5, -1.0], [6.5, 3.0]]);
+    let normal = (a.transpose() * a).try_inverse().unwrap() * a.transpose() * y;
+
+    assert!(approx_eq(&a.qr().solve(y).unwrap(), &normal));
+
+    let deficient = Matrix([[1.0, 2.0], [2.0, 4.0], [0.0, 0.0]]);
+
+    assert_eq!(deficient.qr().solve(Matrix([[1.0], [2.0], [3.0]])), None);
+
+    // rounding leaves tiny, but nonzero, diagonal elements in `R` for these
+    let deficient = Matrix([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0], [10.0, 11.0, 12.0]]).qr();
+
+    assert!(deficient.is_rank_deficient());
+    assert!(!deficient.is_rank_deficient_with(0.0));
+    assert_eq!(deficient.solve(Matrix([[1.0], [0.0], [0.0], [0.0]])), None);
+
+    let deficient = (Matrix([[1.0, 3.0], [2.0, 6.0], [3.0, 9.0]]) * 0.1).qr();
+
+    assert_eq!(deficient.solve(Matrix([[1.0], [0.0], [0.0]])), None);
+
+    // but a small matrix is fine, as long as its columns are independent
+    let small = (a * 1e-12).qr();
+
+    assert!(!small.is_rank_deficient());
+    assert!(approx_eq(&(small.solve(y).unwrap() * 1e-12), &normal));
+}
+
+#[test]
+fn test_rectangular_cols() {
+    let mut m = Matrix([[1, 2, 3], [4, 5, 6]]);
+
+    assert_eq!(m.cols().len(), 3);
+    assert_eq!(m.cols().nth(2).unwrap().collect::<Vec<_>>(), [&3, &6]);
+    assert_eq!(m.cols_mut().len(), 3);
+
+    assert_eq!(m * Matrix([[1], [0], [1]]), Matrix([[4], [10]]));
+}
+
+#[test]
+fn test_cholesky() {
+    let m = Matrix([[4.0, 12.0, -16.0], [12.0, 37.0, -43.0], [-16.0, -43.0, 98.0]]);
+
+    let chol = m.cholesky().unwrap();
+    let l = chol.l();
+
+    assert!(approx_eq(&l, &Matrix([[2.0, 0.0, 0.0], [6.0, 1.0, 0.0], [-8.0, 5.0, 3.0]])));
+    assert!(approx_eq(&(l * l.transpose()), &m));
+    assert!((chol.det() - m.det()).abs() < 1e-9);
+    assert!(approx_eq(&chol.inverse(), &m.try_inverse().unwrap()));
+
+    let b = Matrix([[1.0, 0.0], [2.0, 1.0], [3.0, -1.0]]);
+
+    assert!(approx_eq(&(m * chol.solve(b)), &b));
+
+    let x = Matrix([[1.0], [-2.0], [0.5]]);
+
+    let mut updated = chol;
+    updated.rank_one_update(x);
+
+    let expected = (m + x * x.transpose()).cholesky().unwrap();
+
+    assert!(approx_eq(&updated.l(), &expected.l()));
+
+    assert!(updated.rank_one_downdate(x));
+    assert!(approx_eq(&updated.l(), &l));
+
+    let mut failed = chol;
+
+    assert!(!failed.rank_one_downdate(Matrix([[3.0], [0.0], [0.0]])));
+    assert_eq!(failed.l(), l);
+
+    assert!(Matrix([[1.0, 2.0], [2.0, 1.0]]).cholesky().is_none());
+    assert!(Matrix([[0.0, 0.0], [0.0, 1.0]]).cholesky().is_none());
+    assert!(Matrix([[-1.0]]).cholesky().is_none());
+}
+
+#[test]
+fn test_symmetric_eigen() {
+    use crate::{JacobiOptions, NoConvergence, SortOrder};
+
+    let m = Matrix([[2.0, 0.0, 0.0], [0.0, 3.0, 4.0], [0.0, 4.0, 9.0]]);
+
+    let eigen = m.symmetric_eigen().unwrap();
+    let values = eigen.eigenvalues();
+    let v = eigen.eigenvectors();
+
+    assert!(values.iter().zip([1.0f64, 2.0, 11.0].iter()).all(|(a, b)| (a - b).abs() < 1e-9));
+    assert!(approx_eq(&(v.transpose() * v), &One::one()));
+
+    let mut d = Matrix::<f64, 3, 3>::zero();
+    for i in 0..3 {
+        d[(i, i)] = values[i];
+    }
+
+    assert!(approx_eq(&(v * d * v.transpose()), &m));
+
+    let descending = m
+        .symmetric_eigen_with(JacobiOptions {
+            sort: SortOrder::Descending,
+            ..JacobiOptions::default()
+        })
+        .unwrap();
+
+    assert!((descending.eigenvalues()[0] - 11.0).abs() < 1e-9);
+    assert!((descending.eigenvalues()[2] - 1.0).abs() < 1e-9);
+
+    let m = Matrix([
+        [4.0, 1.0, -2.0, 2.0, 0.5, 0.0],
+        [1.0, 2.0, 0.0, 1.0, 0.0, 1.5],
+        [-2.0, 0.0, 3.0, -2.0, 1.0, 0.0],
+        [2.0, 1.0, -2.0, -1.0, 0.0, 2.0],
+        [0.5, 0.0, 1.0, 0.0, 5.0, -1.0],
+        [0.0, 1.5, 0.0, 2.0, -1.0, 0.0],
+    ]);
+
+    let eigen = m.symmetric_eigen().unwrap();
+    let (values, v) = (eigen.eigenvalues(), eigen.eigenvectors());
+
+    assert!(values.windows(2).all(|w| w[0] <= w[1]));
+
+    for (i, &value) in values.iter().enumerate() {
+        let col = Matrix([[v[(0, i)]], [v[(1, i)]], [v[(2, i)]], [v[(3, i)]], [v[(4, i)]], [v[(5, i)]]]);
+
+        assert!(approx_eq(&(m * col), &(col * value)));
+    }
+
+    let no_sweeps = JacobiOptions {
+        max_sweeps: 0,
+        ..JacobiOptions::default()
+    };
+
+    assert_eq!(m.symmetric_eigen_with(no_sweeps).unwrap_err(), NoConvergence);
+    assert!(Matrix::<f64, 3, 3>::one().symmetric_eigen_with(no_sweeps).is_ok());
+
+    // squaring these elements would overflow or underflow
+    for &scale in &[1e300, 1e-300_f64] {
+        let values = Matrix([[scale, scale], [scale, scale]])
+            .symmetric_eigen()
+            .unwrap()
+            .eigenvalues();
+
+        assert!(values[0].abs() <= 1e-15 * scale, "{:?}", values);
+        assert!((values[1] / scale - 2.0).abs() <= 1e-15, "{:?}", values);
+    }
+
+    assert_eq!(Matrix([[0.0; 2]; 2]).symmetric_eigen().unwrap().eigenvalues(), [0.0; 2]);
+}
+
+#[test]
+fn test_eigenvalues() {
+    fn sorted<const N: usize>(mut values: [num::Complex<f64>; N]) -> [num::Complex<f64>; N] {
+        values.sort_by(|a, b| (a.re, a.im).partial_cmp(&(b.re, b.im)).unwrap());
+        values
+    }
+
+    fn check<const N: usize>(values: [num::Complex<f64>; N], expected: [(f64, f64); N]) {
+        for (value, &(re, im)) in sorted(values).iter().zip(expected.iter()) {
+            assert!((value.re - re).abs() < 1e-9, "{:?}", values);
+            assert!((value.im - im).abs() < 1e-9, "{:?}", values);
+        }
+    }
+
+    // companion matrix of (x - 1)(x - 2)(x - 3)
+    let m = Matrix([[6.0, -11.0, 6.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]);
+
+    check(m.eigenvalues().unwrap(), [(1.0, 0.0), (2.0, 0.0), (3.0, 0.0)]);
+
+    let rotation = Matrix([[0.0, -1.0], [1.0, 0.0]]);
+
+    check(rotation.eigenvalues().unwrap(), [(0.0, -1.0), (0.0, 1.0)]);
+
+    // companion matrix of (x^2 + 1)(x - 1)(x - 2)
+    let m = Matrix([
+        [3.0, -3.0, 3.0, -2.0],
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+    ]);
+
+    check(
+        m.eigenvalues().unwrap(),
+        [(0.0, -1.0), (0.0, 1.0), (1.0, 0.0), (2.0, 0.0)],
+    );
+
+    // the symmetric case should agree with `symmetric_eigen`
+    let m = Matrix([
+        [4.0, 1.0, -2.0, 2.0, 0.5],
+        [1.0, 2.0, 0.0, 1.0, 0.0],
+        [-2.0, 0.0, 3.0, -2.0, 1.0],
+        [2.0, 1.0, -2.0, -1.0, 0.0],
+        [0.5, 0.0, 1.0, 0.0, 5.0],
+    ]);
+
+    let symmetric = m.symmetric_eigen().unwrap().eigenvalues();
+    let mut expected = [(0.0, 0.0); 5];
+    for (e, &v) in expected.iter_mut().zip(symmetric.iter()) {
+        *e = (v, 0.0);
+    }
+
+    check(m.eigenvalues().unwrap(), expected);
+
+    // the eigenvalues of a general matrix sum to the trace and multiply to the determinant
+    let m = Matrix([
+        [1.0, 2.0, -1.0, 0.5, 3.0, 0.0],
+        [-2.0, 0.5, 4.0, 1.0, 0.0, 1.0],
+        [0.0, 3.0, 1.0, -2.0, 1.0, 0.5],
+        [1.5, 0.0, 2.0, -1.0, 2.0, -3.0],
+        [2.0, -1.0, 0.0, 1.0, 0.0, 2.0],
+        [0.0, 1.0, 1.0, 0.0, -2.0, 1.0],
+    ]);
+
+    let values = m.eigenvalues().unwrap();
+    let sum = values.iter().fold(num::Complex::new(0.0f64, 0.0), |acc, &x| acc + x);
+    let product = values.iter().fold(num::Complex::new(1.0f64, 0.0), |acc, &x| acc * x);
+
+    assert!((sum.re - 2.5).abs() < 1e-9 && sum.im.abs() < 1e-9);
+    assert!((product.re - m.det()).abs() < 1e-9 && product.im.abs() < 1e-9);
+
+    check(Matrix([[5.0]]).eigenvalues().unwrap(), [(5.0, 0.0)]);
+    assert_eq!(Matrix::<f64, 0, 0>([]).eigenvalues().unwrap().len(), 0);
+}
+
+#[test]
+fn test_svd() {
+    fn reconstruct<const N: usize, const M: usize>(svd: &crate::Svd<f64, N, M>) -> Matrix<f64, N, M> {
+        let mut s = Matrix::<f64, M, M>::zero();
+
+        for (i, &value) in svd.singular_values().iter().enumerate() {
+            s[(i, i)] = value;
+        }
+
+        svd.u() * s * svd.v_t()
+    }
+
+    let m = Matrix([[3.0, 2.0], [2.0, 3.0], [2.0, -2.0]]);
+    let svd = m.svd().unwrap();
+
+    assert!(approx_eq(&reconstruct(&svd), &m));
+    assert!(approx_eq(&(svd.u().transpose() * svd.u()), &One::one()));
+    assert!(approx_eq(&(svd.v_t() * svd.v_t().transpose()), &One::one()));
+    assert!((svd.singular_values()[0] - 5.0).abs() < 1e-9);
+    assert!((svd.singular_values()[1] - 3.0).abs() < 1e-9);
+    assert!((m.norm2().unwrap() - 5.0).abs() < 1e-9);
+    assert!((m.condition_number().unwrap() - 5.0 / 3.0).abs() < 1e-9);
+    assert_eq!(m.rank(1e-9).unwrap(), 2);
+
+    let normal = (m.transpose() * m).try_inverse().unwrap() * m.transpose();
+
+    assert!(approx_eq(&m.pseudo_inverse(1e-9).unwrap(), &normal));
+
+    let deficient = Matrix([[1.0f64, 2.0, 3.0], [2.0, 4.0, 6.0], [1.0, 0.0, 1.0]]);
+    let svd = deficient.svd().unwrap();
+
+    assert_eq!(svd.rank(1e-9), 2);
+    assert!(svd.singular_values()[2].abs() < 1e-9);
+    assert!(approx_eq(&reconstruct(&svd), &deficient));
+    assert!(deficient.condition_number().unwrap() > 1e9);
+
+    let pinv = svd.pseudo_inverse(1e-9);
+
+    assert!(approx_eq(&(deficient * pinv * deficient), &deficient));
+    assert!(approx_eq(&(pinv * deficient * pinv), &pinv));
+
+    let b = Matrix([[1.0], [2.0], [1.0]]);
+    let x = svd.solve(b, 1e-9);
+
+    assert!(approx_eq(&(deficient * x), &b));
+
+    let wide = Matrix([[1.0, 0.0, 2.0], [0.0, 3.0, 0.0]]);
+    let svd = wide.svd().unwrap();
+
+    assert!(approx_eq(&reconstruct(&svd), &wide));
+    assert!((svd.singular_values()[0] - 3.0).abs() < 1e-9);
+    assert!((svd.singular_values()[1] - 5.0f64.sqrt()).abs() < 1e-9);
+    assert!(svd.singular_values()[2].abs() < 1e-9);
+    assert_eq!(wide.rank(1e-9).unwrap(), 2);
+    assert!((wide.condition_number().unwrap() - 3.0 / 5.0f64.sqrt()).abs() < 1e-9);
+
+    // the rotations never settle on NaNs, which is reported instead of returning garbage
+    let nan = Matrix([[1.0, f64::NAN], [0.0, 1.0]]);
+
+    assert_eq!(nan.svd().err(), Some(crate::NoConvergence));
+    assert_eq!(nan.rank(1e-9), Err(crate::NoConvergence));
+    assert_eq!(crate::NoConvergence.to_string(), "the iterative algorithm did not converge");
+
+    // squaring these elements would overflow or underflow
+    for &scale in &[1e200, 1e-200_f64] {
+        let m = Matrix([[scale, 0.0], [0.0, 2.0 * scale]]);
+        let values = m.svd().unwrap().singular_values();
+
+        assert!((values[0] / scale - 2.0).abs() < 1e-12, "{:?}", values);
+        assert!((values[1] / scale - 1.0).abs() < 1e-12, "{:?}", values);
+        assert_eq!(m.rank(scale * 1e-9).unwrap(), 2);
+        assert!(approx_eq(&(m.pseudo_inverse(scale * 1e-9).unwrap() * m), &One::one()));
+    }
+}
+
+#[test]
+fn test_vector() {
+    use crate::{ColVector, RowVector};
+
+    let m = Matrix([[15, 20], [30, 45]]);
+    let v: ColVector<i32, 2> = Matrix::col_vector([1, 1]);
+
+    assert_eq!(v, Matrix([[1, 1]]).T());
+    assert_eq!(m * v, Matrix::col_vector([35, 75]));
+    assert_eq!(Matrix::row_vector([1, 1]) * m, Matrix::row_vector([45, 65]));
+
+    let a = Matrix::col_vector([1, 2, 3]);
+    let b = Matrix::col_vector([4, 5, 6]);
+
+    assert_eq!(a.dot(&b), 32);
+    assert_eq!(a.norm_squared(), 14);
+    assert_eq!(a.cross(&b), Matrix::col_vector([-3, 6, -3]));
+    assert_eq!(a.cross(&b).dot(&a), 0);
+
+    let r: RowVector<i32, 3> = Matrix::row_vector([1, 2, 3]);
+
+    assert_eq!(r.cross(&Matrix::row_vector([4, 5, 6])), Matrix::row_vector([-3, 6, -3]));
+    assert_eq!(r * a, Matrix([[14]]));
+
+    assert_eq!(Matrix::col_vector([1, 0]).perp_dot(&Matrix::col_vector([0, 1])), 1);
+    assert_eq!(Matrix::row_vector([0, 1]).perp_dot(&Matrix::row_vector([1, 0])), -1);
+
+    let x = Matrix::col_vector([3.0, 4.0]);
+    let y = Matrix::col_vector([2.0, 0.0]);
+
+    assert_eq!(x.norm(), 5.0);
+    assert!(approx_eq(&x.normalize(), &Matrix::col_vector([0.6, 0.8])));
+    assert!(approx_eq(&x.project_onto(&y), &Matrix::col_vector([3.0, 0.0])));
+    assert!((x.angle_between(&y) - 0.6f64.acos()).abs() < 1e-12);
+    assert!((y.angle_between(&(y * -1.0)) - std::f64::consts::PI).abs() < 1e-12);
+}
+
+#[test]
+fn test_view() {
+    let mut m = Matrix([[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]]);
+
+    let v = m.fixed_view::<2, 3>(1, 1);
+
+    assert_eq!(v[(0, 0)], 6);
+    assert_eq!(v[(1, 2)], 12);
+    assert_eq!(v.get(2, 0), None);
+    assert_eq!(v.to_matrix(), Matrix([[6, 7, 8], [10, 11, 12]]));
+
+    let mut rows = v.rows();
+
+    assert!(rows.next_back().unwrap().eq([10, 11, 12].iter()));
+    assert!(rows.next().unwrap().eq([6, 7, 8].iter()));
+    assert!(rows.next().is_none());
+
+    let mut cols = v.cols();
+
+    assert!(cols.nth(1).unwrap().eq([7, 11].iter()));
+    assert!(cols.next_back().unwrap().eq([8, 12].iter()));
+    assert!(cols.next().is_none());
+
+    assert_eq!(v.map(|&x| x * 2), Matrix([[12, 14, 16], [20, 22, 24]]));
+    assert_eq!(
+        v.zip_with(m.fixed_view::<2, 3>(0, 0), |&a, &b| a - b),
+        Matrix([[5, 5, 5], [5, 5, 5]])
+    );
+    assert_eq!(
+        v.zip_with(&Matrix([[1, 1, 1], [2, 2, 2]]), |&a, &b| a * b),
+        Matrix([[6, 7, 8], [20, 22, 24]])
+    );
+
+    let mut v = m.fixed_view_mut::<2, 2>(0, 2);
+
+    v[(1, 1)] = 0;
+
+    for row in v.rows_mut().rev() {
+        for x in row {
+            *x *= 10;
+        }
+    }
+
+    v.zip_with(&Matrix([[1, 1], [1, 1]]), |a, &b| *a += b);
+
+    assert_eq!(m, Matrix([[1, 2, 31, 41], [5, 6, 71, 1], [9, 10, 11, 12]]));
+
+    m.fixed_view_mut::<3, 1>(0, 0).map(|x| *x = 0);
+
+    assert_eq!(m.fixed_view::<3, 1>(0, 0).to_matrix(), Matrix([[0], [0], [0]]));
+    assert_eq!(m.fixed_view::<0, 4>(3, 0).to_matrix(), Matrix::<i32, 0, 4>([]));
+    assert_eq!(m.fixed_view::<3, 0>(0, 4).rows().count(), 3);
+
+    let result = std::panic::catch_unwind(|| {
+        let m = Matrix([[0; 4]; 3]);
+        m.fixed_view::<2, 2>(2, 0).get(0, 0).cloned()
+    });
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_blocks() {
+    let a = Matrix([[1, 2], [3, 4]]);
+    let b = Matrix([[5], [6]]);
+    let c = Matrix([[7, 8]]);
+    let d = Matrix([[9]]);
+
+    let m: Matrix<_, 2, 3> = Matrix::hstack(a, b);
+
+    assert_eq!(m, Matrix([[1, 2, 5], [3, 4, 6]]));
+    assert_eq!(m.split_cols::<2, 1>(), (a, b));
+
+    let m: Matrix<_, 3, 2> = Matrix::vstack(a, c);
+
+    assert_eq!(m, Matrix([[1, 2], [3, 4], [7, 8]]));
+    assert_eq!(m.split_rows::<2, 1>(), (a, c));
+
+    let m: SquareMatrix<_, 3> = Matrix::from_blocks(a, b, c, d);
+
+    assert_eq!(m, Matrix([[1, 2, 5], [3, 4, 6], [7, 8, 9]]));
+    assert_eq!(m.split_blocks::<2, 2, 1, 1>(), (a, b, c, d));
+
+    let (top, bottom) = m.split_rows::<0, 3>();
+
+    assert_eq!(top, Matrix([]));
+    assert_eq!(bottom, m);
+
+    let result = std::panic::catch_unwind(|| Matrix::<i32, 2, 4>::hstack(a, b));
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_unit_map() {
+    let mut m = Matrix([[1, 2], [3, 4]]);
+    let mut sum = 0;
+
+    let unit: Matrix<(), 2, 2> = (&m).map(|x| sum += x);
+
+    assert_eq!(sum, 10);
+    assert_eq!(unit, Matrix([[(); 2]; 2]));
+
+    (&mut m).map(|x| *x *= 2);
+
+    assert_eq!(m, Matrix([[2, 4], [6, 8]]));
+
+    (&mut m).zip_with(Matrix([[1, 1], [1, 1]]), |x, y| *x += y);
+
+    assert_eq!(m, Matrix([[3, 5], [7, 9]]));
+
+    let mut seen = Vec::new();
+
+    m.zip_with(&Matrix([[0, 1], [2, 3]]), |x, &y| seen.push((x, y)));
+
+    assert_eq!(seen, [(3, 0), (5, 1), (7, 2), (9, 3)]);
+}
+
+/// A `2x2` matrix of clones of `rc`, so leaks and double drops show up in `Rc::strong_count`
+fn rc_matrix(rc: &std::rc::Rc<()>) -> Matrix<std::rc::Rc<()>, 2, 2> {
+    Matrix([[rc.clone(), rc.clone()], [rc.clone(), rc.clone()]])
+}
+
+/// Maps a `rc_matrix` by value with `f`, but panics on the third element instead,
+/// and checks that every clone was dropped exactly once
+fn check_map_panic<U>(mut f: impl FnMut(std::rc::Rc<()>) -> U) {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    use std::rc::Rc;
+
+    let rc = Rc::new(());
+    let m = rc_matrix(&rc);
+    let mut count = 0;
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        m.map(|x| {
+            count += 1;
+
+            if count == 3 {
+                panic!();
+            }
+
+            f(x)
+        })
+    }));
+
+    assert!(result.is_err());
+    assert_eq!(count, 3);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn test_unit_map_panic() {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    use std::rc::Rc;
+
+    assert_eq!(std::mem::size_of::<Matrix<(), 3, 4>>(), 0);
+
+    check_map_panic(drop);
+
+    let rc = Rc::new(());
+    let m = rc_matrix(&rc);
+    let o = rc_matrix(&rc);
+
+    let result = catch_unwind(AssertUnwindSafe(|| m.zip_with(o, |_, _| panic!())));
+
+    assert!(result.is_err());
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn test_cols_mut() {
+    let mut m = Matrix([[3.0, 1.0, 0.0], [4.0, 0.0, 2.0]]);
+
+    for col in m.cols_mut() {
+        let col = col.collect::<Vec<_>>();
+        let norm = col.iter().map(|x| **x * **x).sum::<f64>().sqrt();
+
+        for x in col {
+            *x /= norm;
+        }
+    }
+
+    assert_eq!(m, Matrix([[0.6, 1.0, 0.0], [0.8, 0.0, 1.0]]));
+
+    let mut m = Matrix([[1, 2, 3], [4, 5, 6]]);
+
+    {
+        let mut cols = m.cols_mut();
+        let mut first = cols.next().unwrap();
+        let mut last = cols.next_back().unwrap();
+
+        // references into different columns can be held at the same time
+        let a = first.next().unwrap();
+        let b = last.next_back().unwrap();
+        let c = first.next_back().unwrap();
+
+        std::mem::swap(a, b);
+        *c *= 10;
+
+        assert!(last.eq([&mut 3]));
+        assert!(cols.next().unwrap().eq([&mut 2, &mut 5]));
+        assert!(cols.next().is_none());
+    }
+
+    assert_eq!(m, Matrix([[6, 2, 3], [40, 5, 1]]));
+
+    let mut m = Matrix([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    let mut v = m.fixed_view_mut::<2, 2>(1, 1);
+
+    for (i, col) in v.cols_mut().enumerate() {
+        col.for_each(|x| *x = i);
+    }
+
+    assert_eq!(m, Matrix([[1, 2, 3], [4, 0, 1], [7, 0, 1]]));
+}
+
+// The following tests are written to be run under Miri (`cargo miri test`) as well,
+// so they stick to small matricies and count drops with `Rc` to catch leaks and double drops
+
+#[test]
+fn test_collect_branches() {
+    use std::rc::Rc;
+
+    let rc = Rc::new(());
+
+    let arr: [Rc<()>; 3] = crate::checked::collect_array(vec![rc.clone(), rc.clone(), rc.clone()]);
+    let mat: Matrix<_, 2, 3> = crate::checked::collect_mat(vec![arr.clone(), arr]);
+
+    assert_eq!(Rc::strong_count(&rc), 7);
+
+    let arr: [Rc<()>; 3] =
+        unsafe { crate::unchecked::collect_array(vec![rc.clone(), rc.clone(), rc.clone()]) };
+    let other: Matrix<_, 2, 3> = unsafe { crate::unchecked::collect_mat(vec![arr.clone(), arr]) };
+
+    assert_eq!(Rc::strong_count(&rc), 13);
+
+    drop((mat, other));
+
+    assert_eq!(Rc::strong_count(&rc), 1);
+
+    let empty: [Rc<()>; 0] = crate::checked::collect_array(None);
+    let empty_too: [Rc<()>; 0] = unsafe { crate::unchecked::collect_array(None) };
+
+    assert_eq!(empty.len() + empty_too.len(), 0);
+
+    let result = std::panic::catch_unwind(|| {
+        let arr: [Rc<()>; 3] = crate::checked::collect_array(vec![Rc::new(())]);
+        arr
+    });
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_map_panic() {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    use std::rc::Rc;
+
+    check_map_panic(|x| x);
+
+    let rc = Rc::new(());
+    let m = rc_matrix(&rc);
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        (&m).map(|x| if Rc::strong_count(x) > 6 { panic!() } else { x.clone() })
+    }));
+
+    assert!(result.is_err());
+    assert_eq!(Rc::strong_count(&rc), 5);
+
+    drop(m);
+
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn test_into_iter_early_drop() {
+    use std::rc::Rc;
+
+    let rc = Rc::new(());
+    let m = Matrix([[rc.clone(), rc.clone(), rc.clone()], [rc.clone(), rc.clone(), rc.clone()]]);
+
+    let mut cols = m.into_cols();
+    let first = cols.next().unwrap();
+    let last = cols.next_back().unwrap();
+
+    assert_eq!(cols.len(), 1);
+
+    drop(cols);
+
+    assert_eq!(Rc::strong_count(&rc), 5);
+
+    drop((first, last));
+
+    assert_eq!(Rc::strong_count(&rc), 1);
+
+    let m = Matrix([[rc.clone(), rc.clone(), rc.clone()], [rc.clone(), rc.clone(), rc.clone()]]);
+
+    let mut rows = m.into_rows();
+    let mut row = rows.next().unwrap();
+
+    assert!(row.nth(1).is_some());
+    assert!(rows.nth(1).is_none());
+    assert_eq!(Rc::strong_count(&rc), 2);
+
+    drop(row);
+
+    assert_eq!(Rc::strong_count(&rc), 1);
+
+    let mut row = Matrix([[1, 2, 3, 4]]).into_rows().next().unwrap();
+
+    assert_eq!(row.nth_back(1), Some(3));
+    assert_eq!(row.nth_back(0), Some(2));
+    assert_eq!(row.next(), Some(1));
+}
+
+#[test]
+fn test_zero_sized() {
+    let m = Matrix::<i32, 0, 3>([]);
+
+    assert_eq!(m.into_cols().len(), 3);
+    assert_eq!(m.into_cols().count(), 3);
+    assert_eq!(m.cols().count(), 3);
+    assert_eq!(m.rows().count(), 0);
+    assert_eq!(m.transpose(), Matrix([[], [], []]));
+    assert_eq!(m.transpose() * m, Matrix::<i32, 3, 3>::zero());
+    assert_eq!(m * m.transpose(), Matrix([]));
+
+    let mut m = Matrix::<String, 3, 0>([[], [], []]);
+
+    assert_eq!(m.rows_mut().count(), 3);
+    assert_eq!(m.cols_mut().count(), 0);
+    assert!(m.rows_mut().all(|row| row.len() == 0));
+    assert_eq!((&m).map(|x| x.len()).transpose(), Matrix([]));
+    assert_eq!(m.get_all_mut([(0, 0), (1, 0)]), [None, None]);
+
+    let mut m = Matrix([[(), ()], [(), ()]]);
+
+    assert_eq!(m.cols_mut().flatten().count(), 4);
+    assert_eq!(m.get_all_mut([(1, 1), (0, 1)]), [Some(&mut ()), Some(&mut ())]);
+}
+
+#[test]
+fn test_get_all_mut_aliasing() {
+    let mut m = Matrix([[0, 1, 2], [3, 4, 5]]);
+
+    // all of the references must stay valid while the others are made and used
+    let [a, b, c, d] = m.get_all_mut([(1, 2), (0, 0), (1, 2), (0, 1)]);
+
+    let (a, b, d) = (a.unwrap(), b.unwrap(), d.unwrap());
+
+    assert!(c.is_none());
+
+    *a += 10;
+    *b += 20;
+    *d += *a;
+    *a += 1;
+
+    assert_eq!(m, Matrix([[20, 16, 2], [3, 4, 16]]));
+}
+
+/// Counts how many times it was dropped
+#[derive(Clone)]
+struct DropCounter<'a>(&'a std::cell::Cell<usize>);
+
+impl Drop for DropCounter<'_> {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+#[test]
+fn test_panic_safety() {
+    use crate::{ForBoth, ForEach};
+    use std::cell::Cell;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    let drops = Cell::new(0);
+    let counters = || Matrix([[0, 1, 2], [3, 4, 5]]).map(|i| (i, Box::new(DropCounter(&drops))));
+
+    // by value, panicking in the second row after some outputs were made
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        counters().map(|(i, x)| if i == 4 { panic!() } else { x })
+    }));
+
+    assert!(result.is_err());
+    assert_eq!(drops.get(), 6);
+
+    drops.set(0);
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        counters().zip_with(counters(), |(i, x), (_, y)| if i == 2 { panic!() } else { (x, y) })
+    }));
+
+    assert!(result.is_err());
+    assert_eq!(drops.get(), 12);
+
+    drops.set(0);
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        counters().for_each(|(i, _)| assert_ne!(i, 3));
+    }));
+
+    assert!(result.is_err());
+    assert_eq!(drops.get(), 6);
+
+    drops.set(0);
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        counters().for_both(counters(), |(i, _), _| assert_ne!(i, 5));
+    }));
+
+    assert!(result.is_err());
+    assert_eq!(drops.get(), 12);
+
+    drops.set(0);
+
+    // through references, only the outputs are dropped
+    let m = counters();
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        (&m).map(|(i, x)| if *i == 5 { panic!() } else { x.clone() })
+    }));
+
+    assert!(result.is_err());
+    assert_eq!(drops.get(), 5);
+
+    drop(m);
+
+    assert_eq!(drops.get(), 11);
+
+    drops.set(0);
+
+    // the columns that are being built up while iterating over the columns
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        counters().into_cols().for_each(|col| {
+            col.for_each(|(i, _)| assert_ne!(i, 4));
+        })
+    }));
+
+    assert!(result.is_err());
+    assert_eq!(drops.get(), 6);
+
+    drops.set(0);
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let m: Matrix<_, 3, 2> = Matrix::from_blocks(
+            counters().transpose(),
+            Matrix::<_, 3, 0>([[], [], []]),
+            Matrix::<_, 0, 2>([]),
+            Matrix::<_, 0, 0>([]),
+        );
+
+        m.map(|(i, _)| assert_ne!(i, 1))
+    }));
+
+    assert!(result.is_err());
+    assert_eq!(drops.get(), 6);
+}
+
+#[test]
+fn test_display() {
+    use crate::DisplayOptions;
+
+    let m = Matrix([[1, -20, 3], [400, 5, 6]]);
+
+    assert_eq!(m.to_string(), "⎡   1 -20 3 ⎤\n⎣ 400   5 6 ⎦");
+    assert_eq!(format!("{:<3}", m), "⎡ 1   -20 3   ⎤\n⎣ 400 5   6   ⎦");
+    assert_eq!(Matrix([[1, 2]]).to_string(), "[ 1 2 ]");
+    assert_eq!(format!("{:*^4}", Matrix([[1, 20]])), "[ *1** *20* ]");
+    assert_eq!(format!("{:+}", Matrix([[1, -20], [0, 3]])), "⎡ +1 -20 ⎤\n⎣ +0  +3 ⎦");
+    assert_eq!(format!("{:03}", Matrix([[1, -20], [400, 5]])), "⎡ 001 -20 ⎤\n⎣ 400 005 ⎦");
+    assert_eq!(format!("{:+06.1}", Matrix([[1.0, -2.5]])), "[ +001.0 -002.5 ]");
+    assert_eq!(format!("{:+.1e}", Matrix([[1500.0, -0.03]])), "[ +1.5e3 -3.0e-2 ]");
+    assert_eq!(Matrix::<i32, 0, 3>([]).to_string(), "[ ]");
+
+    let m = Matrix([[0.5, 1.25], [-3.0, 100.0], [2.0, 0.126]]);
+
+    assert_eq!(
+        format!("{:6.2}", m),
+        "⎡   0.50   1.25 ⎤\n⎢  -3.00 100.00 ⎥\n⎣   2.00   0.13 ⎦"
+    );
+    assert_eq!(
+        format!("{:.1e}", Matrix([[1500.0, -0.03]])),
+        "[ 1.5e3 -3.0e-2 ]"
+    );
+    assert_eq!(format!("{:e}", Matrix([[1, 20]])), "[ 1e0 2e1 ]");
+
+    let mut big = Matrix([[0; 10]; 10]);
+    big.iter_mut()
+        .flat_map(|row| row.iter_mut())
+        .enumerate()
+        .for_each(|(i, x)| *x = i);
+
+    let options = DisplayOptions {
+        max_rows: 3,
+        max_cols: 4,
+    };
+
+    assert_eq!(
+        big.display_with(options).to_string(),
+        "⎡  0  1 …  8  9 ⎤\n⎢ 10 11 … 18 19 ⎥\n⎢  ⋮  ⋮ ⋱  ⋮  ⋮ ⎥\n⎣ 90 91 … 98 99 ⎦"
+    );
+    assert_eq!(
+        big.display_with(DisplayOptions {
+            max_rows: 10,
+            max_cols: 10
+        })
+        .to_string(),
+        big.to_string()
+    );
+}
+
+#[test]
+fn test_parse() {
+    use crate::ParseMatrixError;
+
+    let m = Matrix([[1, 2, 3], [4, 5, 6]]);
+
+    assert_eq!("[1 2 3; 4 5 6]".parse(), Ok(m));
+    assert_eq!("[1, 2, 3\n 4, 5, 6]".parse(), Ok(m));
+    assert_eq!("[[1,2,3],[4,5,6]]".parse(), Ok(m));
+    assert_eq!("[[1 2 3]\n [4 5 6]]".parse(), Ok(m));
+    assert_eq!("1,2,3\n4,5,6\n".parse(), Ok(m));
+    assert_eq!("  1 2 3\n\n  4 5 6".parse(), Ok(m));
+    assert_eq!(
+        m.to_string().replace(['⎡', '⎤', '⎣', '⎦'], "").parse(),
+        Ok(m)
+    );
+    assert_eq!("[]".parse(), Ok(Matrix::<i32, 0, 2>([])));
+    assert_eq!("[[], []]".parse(), Ok(Matrix::<i32, 2, 0>([[], []])));
+
+    let m: Matrix<f64, 2, 2> = "[0.5 -1e3; inf 2]".parse().unwrap();
+
+    assert_eq!(m, Matrix([[0.5, -1000.0], [f64::INFINITY, 2.0]]));
+
+    type Result = std::result::Result<Matrix<i32, 2, 3>, ParseMatrixError<std::num::ParseIntError>>;
+
+    assert_eq!(
+        "[1 2 3]".parse::<Matrix<i32, 2, 3>>(),
+        Err(ParseMatrixError::Rows {
+            expected: 2,
+            found: 1
+        })
+    );
+    assert_eq!(
+        "1,2,3\n4,5".parse::<Matrix<i32, 2, 3>>(),
+        Err(ParseMatrixError::Cols {
+            row: 1,
+            expected: 3,
+            found: 2
+        })
+    );
+    assert_eq!(
+        "1,2,3\n4,,6".parse::<Matrix<i32, 2, 3>>(),
+        Err(ParseMatrixError::Element {
+            row: 1,
+            col: 1,
+            error: "".parse::<i32>().unwrap_err()
+        })
+    );
+
+    let err: Result = "[1 2 x; 4 5 6]".parse();
+    assert_eq!(
+        err.unwrap_err().to_string(),
+        "invalid element at (0, 2): invalid digit found in string"
+    );
+
+    for (s, offset) in [
+        ("[1 2 3; 4 5 6", 13),
+        ("1 2 3; 4 5 6]", 12),
+        ("[[1 2 3] 4 5 6]", 9),
+        ("[[1 2 3], [4 [5] 6]]", 13),
+        ("[1 2 3] [4 5 6]", 6),
+        ("  [[1 2 3], [4 5 6]", 12),
+    ] {
+        let err: Result = s.parse();
+
+        assert_eq!(err, Err(ParseMatrixError::Brackets { offset }), "{}", s);
+    }
+
+    let err: Result = "[[1 2 3], 4 5 6]".parse();
+    assert_eq!(
+        err.unwrap_err().to_string(),
+        "the brackets are unbalanced or misplaced at byte 10"
+    );
+}
+
+#[test]
+fn test_const_fn() {
+    const ROTATE: Matrix<i32, 2, 2> = Matrix([[0, -1], [1, 0]]);
+    const HALF_TURN: Matrix<i32, 2, 2> = ROTATE.const_mul(&ROTATE);
+    const POINTS: Matrix<f64, 3, 2> = Matrix([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+    static GRAM: Matrix<f64, 2, 2> = POINTS.const_transpose().const_mul(&POINTS);
+    static I: Matrix<u8, 3, 3> = Matrix::identity();
+
+    assert_eq!(HALF_TURN, ROTATE * ROTATE);
+    assert_eq!(HALF_TURN, -Matrix::<i32, 2, 2>::identity());
+    assert_eq!(GRAM, POINTS.transpose() * POINTS);
+    assert_eq!(I, SquareMatrix::<u8, 3>::one());
+    assert_eq!(Matrix::<f32, 2, 4>::zeros(), Matrix::zero());
+    assert_eq!(POINTS.const_transpose(), POINTS.transpose());
+    assert_eq!(
+        Matrix::<i32, 0, 3>::zeros().const_mul(&Matrix::<i32, 3, 2>::zeros()),
+        Matrix([])
+    );
+    assert_eq!(
+        Matrix::<i32, 2, 0>([[], []]).const_mul(&Matrix::<i32, 0, 2>([])),
+        Matrix::zero()
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde() {
+    use std::collections::BTreeMap;
+
+    let m = Matrix([[1, 2, 3], [4, 5, 6]]);
+
+    assert_eq!(serde_json::to_string(&m).unwrap(), "[[1,2,3],[4,5,6]]");
+    assert_eq!(serde_json::from_str::<Matrix<i32, 2, 3>>("[[1,2,3],[4,5,6]]").unwrap(), m);
+    assert_eq!(serde_json::from_str::<Matrix<i32, 0, 3>>("[]").unwrap(), Matrix([]));
+
+    let too_short = serde_json::from_str::<Matrix<i32, 2, 3>>("[[1,2,3],[4,5]]").unwrap_err();
+    let too_long = serde_json::from_str::<Matrix<i32, 2, 3>>("[[1,2,3],[4,5,6],[7,8,9]]").unwrap_err();
+
+    assert!(too_short.to_string().starts_with("invalid length 2, expected a sequence of 3 elements"));
+    assert!(too_long.to_string().starts_with("invalid length 3, expected a sequence of 2 elements"));
+
+    let too_long = serde_json::from_str::<Matrix<i32, 2, 3>>("[[1,2,3],[4,5,6],[],[],[]]").unwrap_err();
+    let row_too_long = serde_json::from_str::<Matrix<i32, 1, 2>>("[[1,2,3,4,5,6]]").unwrap_err();
+
+    assert!(too_long.to_string().starts_with("invalid length 5, expected a sequence of 2 elements"));
+    assert!(row_too_long.to_string().starts_with("invalid length 6, expected a sequence of 2 elements"));
+
+    let mut config = BTreeMap::new();
+
+    config.insert("calibration", Matrix([[1.0, 0.5], [0.0, 2.0]]));
+
+    let text = toml::to_string(&config).unwrap();
+
+    assert_eq!(text, "calibration = [[1.0, 0.5], [0.0, 2.0]]\n");
+
+    let parsed: BTreeMap<String, Matrix<f64, 2, 2>> = toml::from_str(&text).unwrap();
+
+    assert_eq!(parsed["calibration"], config["calibration"]);
+    assert!(toml::from_str::<BTreeMap<String, Matrix<f64, 2, 2>>>("calibration = [[1.0], [2.0]]").is_err());
+}
@@ -0,0 +1,133 @@
+use crate::{dot, Matrix};
+use num::{Float, Zero};
+
+use std::ops::{Add, Mul, Sub};
+
+/// A column vector, an `N x 1` matrix
+///
+/// You can make one with `Matrix::col_vector([0, 1, 2])`
+pub type ColVector<T, const N: usize> = Matrix<T, { N }, 1>;
+
+/// A row vector, a `1 x M` matrix
+///
+/// You can make one with `Matrix::row_vector([0, 1, 2])`
+pub type RowVector<T, const M: usize> = Matrix<T, 1, { M }>;
+
+impl<T, const N: usize> Matrix<T, { N }, 1> {
+    /// Creates a column vector from the given elements
+    pub fn col_vector(arr: [T; N]) -> Self {
+        Matrix([arr]).transpose()
+    }
+}
+
+impl<T, const M: usize> Matrix<T, 1, { M }> {
+    /// Creates a row vector from the given elements
+    pub fn row_vector(arr: [T; M]) -> Self {
+        Matrix([arr])
+    }
+}
+
+impl<T, const N: usize, const M: usize> Matrix<T, { N }, { M }>
+where
+    T: Add<Output = T> + Mul<Output = T> + Zero + Clone,
+{
+    /// The dot product of two vectors
+    ///
+    /// This works for any shape of matrix, where it is the sum of the products of the corrosponding elements
+    pub fn dot(&self, other: &Self) -> T {
+        dot(
+            self.iter().flat_map(|row| row.iter()),
+            other.iter().flat_map(|row| row.iter()),
+        )
+    }
+
+    /// The square of the euclidean norm
+    pub fn norm_squared(&self) -> T {
+        self.dot(self)
+    }
+}
+
+impl<T: Float, const N: usize, const M: usize> Matrix<T, { N }, { M }> {
+    /// The euclidean norm of a vector, or the Frobenius norm of a matrix
+    ///
+    /// For the operator 2-norm of a matrix, see `norm2`
+    pub fn norm(&self) -> T {
+        self.norm_squared().sqrt()
+    }
+
+    /// Scales the vector to have a norm of one
+    ///
+    /// The zero vector will produce NaNs
+    pub fn normalize(&self) -> Self {
+        *self / self.norm()
+    }
+
+    /// The angle between two vectors in radians, in the range `[0, pi]`
+    pub fn angle_between(&self, other: &Self) -> T {
+        let cos = self.dot(other) / (self.norm() * other.norm());
+
+        cos.max(-T::one()).min(T::one()).acos()
+    }
+
+    /// The projection of this vector onto `other`
+    pub fn project_onto(&self, other: &Self) -> Self {
+        *other * (self.dot(other) / other.norm_squared())
+    }
+}
+
+impl<T> Matrix<T, 3, 1>
+where
+    T: Mul<Output = T> + Sub<Output = T> + Clone,
+{
+    /// The cross product of two 3D vectors
+    pub fn cross(&self, other: &Self) -> Self {
+        let [[a0], [a1], [a2]] = self.0.clone();
+        let [[b0], [b1], [b2]] = other.0.clone();
+
+        Matrix([
+            [a1.clone() * b2.clone() - a2.clone() * b1.clone()],
+            [a2 * b0.clone() - a0.clone() * b2],
+            [a0 * b1 - a1 * b0],
+        ])
+    }
+}
+
+impl<T> Matrix<T, 1, 3>
+where
+    T: Mul<Output = T> + Sub<Output = T> + Clone,
+{
+    /// The cross product of two 3D vectors
+    pub fn cross(&self, other: &Self) -> Self {
+        self.clone().transpose().cross(&other.clone().transpose()).transpose()
+    }
+}
+
+impl<T> Matrix<T, 2, 1>
+where
+    T: Mul<Output = T> + Sub<Output = T> + Clone,
+{
+    /// The perp-dot product of two 2D vectors, the 2D analog of the cross product
+    ///
+    /// This is the z component of the cross product of the two vectors extended with a zero z component
+    pub fn perp_dot(&self, other: &Self) -> T {
+        let [[a0], [a1]] = self.0.clone();
+        let [[b0], [b1]] = other.0.clone();
+
+        a0 * b1 - a1 * b0
+    }
+}
+
+impl<T> Matrix<T, 1, 2>
+where
+    T: Mul<Output = T> + Sub<Output = T> + Clone,
+{
+    /// The perp-dot product of two 2D vectors, the 2D analog of the cross product
+    ///
+    /// This is the z component of the cross product of the two vectors extended with a zero z component
+    pub fn perp_dot(&self, other: &Self) -> T {
+        let [[a0, a1]] = self.0.clone();
+        let [[b0, b1]] = other.0.clone();
+
+        a0 * b1 - a1 * b0
+    }
+}
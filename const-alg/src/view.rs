@@ -0,0 +1,306 @@
+use crate::iter::{Cols, ColsMut, Rows, RowsMut};
+use crate::{collect_array, collect_mat, Map, Matrix, ZipWith};
+
+use std::fmt;
+use std::ops::{Index, IndexMut, Range};
+
+/// A shared view into an `R x C` block of a matrix
+///
+/// You can make one with `Matrix::fixed_view`
+pub struct MatrixView<'a, T, const R: usize, const C: usize> {
+    data: &'a [T],
+    stride: usize,
+}
+
+/// A unique view into an `R x C` block of a matrix
+///
+/// You can make one with `Matrix::fixed_view_mut`
+pub struct MatrixViewMut<'a, T, const R: usize, const C: usize> {
+    data: &'a mut [T],
+    stride: usize,
+}
+
+/// The range of the flattened `N x M` matrix that covers the `R x C` block
+/// starting at `(row, col)`, and the stride to use for it
+///
+/// Panics if the block doesn't fit in the matrix
+fn block<const N: usize, const M: usize, const R: usize, const C: usize>(
+    row: usize,
+    col: usize,
+) -> (Range<usize>, usize) {
+    assert!(
+        row <= N && R <= N - row && col <= M && C <= M - col,
+        "view out of bounds!"
+    );
+
+    if R == 0 || C == 0 {
+        (0..0, 0)
+    } else {
+        let start = row * M + col;
+
+        (start..start + (R - 1) * M + C, M)
+    }
+}
+
+impl<T, const N: usize, const M: usize> Matrix<T, { N }, { M }> {
+    /// Creates a shared view into the `R x C` block of the matrix whose top left corner is at `(row, col)`
+    ///
+    /// Panics if the block doesn't fit in the matrix
+    pub fn fixed_view<const R: usize, const C: usize>(
+        &self,
+        row: usize,
+        col: usize,
+    ) -> MatrixView<'_, T, { R }, { C }> {
+        let (range, stride) = block::<{ N }, { M }, { R }, { C }>(row, col);
+
+        MatrixView {
+            data: &self.flatten()[range],
+            stride,
+        }
+    }
+
+    /// Creates a unique view into the `R x C` block of the matrix whose top left corner is at `(row, col)`
+    ///
+    /// Panics if the block doesn't fit in the matrix
+    pub fn fixed_view_mut<const R: usize, const C: usize>(
+        &mut self,
+        row: usize,
+        col: usize,
+    ) -> MatrixViewMut<'_, T, { R }, { C }> {
+        let (range, stride) = block::<{ N }, { M }, { R }, { C }>(row, col);
+
+        MatrixViewMut {
+            data: &mut self.flatten_mut()[range],
+            stride,
+        }
+    }
+}
+
+impl<'a, T, const R: usize, const C: usize> MatrixView<'a, T, { R }, { C }> {
+    /// Gets an element from the view
+    pub fn get(&self, row: usize, col: usize) -> Option<&'a T> {
+        if row < R && col < C {
+            Some(&self.data[row * self.stride + col])
+        } else {
+            None
+        }
+    }
+
+    /// iterates over shared references to rows of the view
+    pub fn rows(&self) -> Rows<'a, T, { R }, { C }> {
+        Rows::new(self.data, self.stride)
+    }
+
+    /// iterates over shared references to columns of the view
+    pub fn cols(&self) -> Cols<'a, T, { R }, { C }> {
+        Cols::new(self.data, self.stride)
+    }
+
+    /// Copies the elements of the view into a new matrix
+    pub fn to_matrix(&self) -> Matrix<T, { R }, { C }>
+    where
+        T: Clone,
+    {
+        unsafe { collect_mat(self.rows().map(|row| collect_array(row.cloned()))) }
+    }
+}
+
+impl<'a, T, const R: usize, const C: usize> MatrixViewMut<'a, T, { R }, { C }> {
+    /// Reborrows this view as a shared view
+    pub fn as_view(&self) -> MatrixView<'_, T, { R }, { C }> {
+        MatrixView {
+            data: self.data,
+            stride: self.stride,
+        }
+    }
+
+    /// Gets an element from the view
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        self.as_view().get(row, col)
+    }
+
+    /// Gets an element from the view
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if row < R && col < C {
+            Some(&mut self.data[row * self.stride + col])
+        } else {
+            None
+        }
+    }
+
+    /// iterates over unique references to rows of the view
+    pub fn rows_mut(&mut self) -> RowsMut<'_, T, { R }, { C }> {
+        RowsMut::new(self.data, self.stride)
+    }
+
+    /// iterates over unique references to columns of the view
+    pub fn cols_mut(&mut self) -> ColsMut<'_, T, { R }, { C }> {
+        ColsMut::new(self.data, self.stride)
+    }
+
+    /// iterates over shared references to rows of the view
+    pub fn rows(&self) -> Rows<'_, T, { R }, { C }> {
+        Rows::new(self.data, self.stride)
+    }
+
+    /// iterates over shared references to columns of the view
+    pub fn cols(&self) -> Cols<'_, T, { R }, { C }> {
+        Cols::new(self.data, self.stride)
+    }
+
+    /// Copies the elements of the view into a new matrix
+    pub fn to_matrix(&self) -> Matrix<T, { R }, { C }>
+    where
+        T: Clone,
+    {
+        self.as_view().to_matrix()
+    }
+}
+
+impl<T, const R: usize, const C: usize> Clone for MatrixView<'_, T, { R }, { C }> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, const R: usize, const C: usize> Copy for MatrixView<'_, T, { R }, { C }> {}
+
+impl<T: fmt::Debug, const R: usize, const C: usize> fmt::Debug for MatrixView<'_, T, { R }, { C }> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut list = f.debug_list();
+
+        for i in 0..R {
+            let start = i * self.stride;
+
+            list.entry(&&self.data[start..start + C]);
+        }
+
+        list.finish()
+    }
+}
+
+impl<T: fmt::Debug, const R: usize, const C: usize> fmt::Debug for MatrixViewMut<'_, T, { R }, { C }> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_view().fmt(f)
+    }
+}
+
+impl<T, const R: usize, const C: usize> Index<(usize, usize)> for MatrixView<'_, T, { R }, { C }> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        self.get(row, col).expect("position out of bounds!")
+    }
+}
+
+impl<T, const R: usize, const C: usize> Index<(usize, usize)> for MatrixViewMut<'_, T, { R }, { C }> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        self.get(row, col).expect("position out of bounds!")
+    }
+}
+
+impl<T, const R: usize, const C: usize> IndexMut<(usize, usize)> for MatrixViewMut<'_, T, { R }, { C }> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        self.get_mut(row, col).expect("position out of bounds!")
+    }
+}
+
+impl<'a, F, T, U, const R: usize, const C: usize> Map<F> for MatrixView<'a, T, { R }, { C }>
+where
+    F: FnMut(&'a T) -> U,
+{
+    type Output = Matrix<U, { R }, { C }>;
+
+    fn map(self, mut f: F) -> Self::Output {
+        let iter = self.rows().map(|row| unsafe {
+            let arr: [_; C] = collect_array(row.map(&mut f));
+
+            arr
+        });
+
+        unsafe { collect_mat(iter) }
+    }
+}
+
+impl<'a, F, T, U, const R: usize, const C: usize> Map<F> for MatrixViewMut<'a, T, { R }, { C }>
+where
+    F: FnMut(&'a mut T) -> U,
+{
+    type Output = Matrix<U, { R }, { C }>;
+
+    fn map(self, mut f: F) -> Self::Output {
+        let rows = RowsMut::<T, { R }, { C }>::new(self.data, self.stride);
+
+        let iter = rows.map(|row| unsafe {
+            let arr: [_; C] = collect_array(row.map(&mut f));
+
+            arr
+        });
+
+        unsafe { collect_mat(iter) }
+    }
+}
+
+impl<'a, 'b, T, U, V, F, const R: usize, const C: usize> ZipWith<MatrixView<'b, U, { R }, { C }>, F>
+    for MatrixView<'a, T, { R }, { C }>
+where
+    F: FnMut(&'a T, &'b U) -> V,
+{
+    type Output = Matrix<V, { R }, { C }>;
+
+    fn zip_with(self, other: MatrixView<'b, U, { R }, { C }>, mut f: F) -> Self::Output {
+        let iter = self.rows().zip(other.rows()).map(|(s, o)| unsafe {
+            let arr: [_; C] = collect_array(s.zip(o).map(|(s, o)| f(s, o)));
+
+            arr
+        });
+
+        unsafe { collect_mat(iter) }
+    }
+}
+
+impl<'a, 'b, T, U, V, F, const R: usize, const C: usize> ZipWith<&'b Matrix<U, { R }, { C }>, F>
+    for MatrixView<'a, T, { R }, { C }>
+where
+    F: FnMut(&'a T, &'b U) -> V,
+{
+    type Output = Matrix<V, { R }, { C }>;
+
+    fn zip_with(self, other: &'b Matrix<U, { R }, { C }>, f: F) -> Self::Output {
+        self.zip_with(other.fixed_view::<{ R }, { C }>(0, 0), f)
+    }
+}
+
+impl<'a, 'b, T, U, V, F, const R: usize, const C: usize> ZipWith<MatrixView<'b, U, { R }, { C }>, F>
+    for MatrixViewMut<'a, T, { R }, { C }>
+where
+    F: FnMut(&'a mut T, &'b U) -> V,
+{
+    type Output = Matrix<V, { R }, { C }>;
+
+    fn zip_with(self, other: MatrixView<'b, U, { R }, { C }>, mut f: F) -> Self::Output {
+        let rows = RowsMut::<T, { R }, { C }>::new(self.data, self.stride);
+
+        let iter = rows.zip(other.rows()).map(|(s, o)| unsafe {
+            let arr: [_; C] = collect_array(s.zip(o).map(|(s, o)| f(s, o)));
+
+            arr
+        });
+
+        unsafe { collect_mat(iter) }
+    }
+}
+
+impl<'a, 'b, T, U, V, F, const R: usize, const C: usize> ZipWith<&'b Matrix<U, { R }, { C }>, F>
+    for MatrixViewMut<'a, T, { R }, { C }>
+where
+    F: FnMut(&'a mut T, &'b U) -> V,
+{
+    type Output = Matrix<V, { R }, { C }>;
+
+    fn zip_with(self, other: &'b Matrix<U, { R }, { C }>, f: F) -> Self::Output {
+        self.zip_with(other.fixed_view::<{ R }, { C }>(0, 0), f)
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{collect_array, collect_mat, into_iter, ForBoth, Matrix};
+use crate::{collect_array, collect_mat, into_iter, Matrix};
 
 /// Zips the matrix eagerly with the given function
 /// 
@@ -7,6 +7,16 @@ use crate::{collect_array, collect_mat, into_iter, ForBoth, Matrix};
 /// This takes a secondary matrix, and a function that accepts two arguments,
 /// elements into each matrix, and calls the function with all of the corrosponding elements of each matrix
 /// and produces a matrix of the result 
+/// 
+/// If the function returns `()`, then the output is a `Matrix<(), N, M>`, which doesn't take up any space,
+/// but if you only need the side effects, `ForBoth` says that more clearly
+/// 
+/// # Panic safety
+/// 
+/// If the function panics, the outputs it already returned are dropped along with the partially built matrix,
+/// and so are the remaining pairs from whichever of the two matricies were taken by value.
+/// The elements that were passed to the function belong to it, so they aren't dropped again.
+/// See the [crate docs](crate#panic-safety)
 pub trait ZipWith<RHS, F> {
     type Output;
 
@@ -20,7 +30,7 @@ where
 {
     type Output = Matrix<V, { N }, { M }>;
 
-    default fn zip_with(self, other: Matrix<U, { N }, { M }>, mut f: F) -> Self::Output {
+    fn zip_with(self, other: Matrix<U, { N }, { M }>, mut f: F) -> Self::Output {
         let Matrix(s) = self;
         let Matrix(o) = other;
 
@@ -44,7 +54,7 @@ where
 {
     type Output = Matrix<V, { N }, { M }>;
 
-    default fn zip_with(self, other: &'b mut Matrix<U, { N }, { M }>, mut f: F) -> Self::Output {
+    fn zip_with(self, other: &'b mut Matrix<U, { N }, { M }>, mut f: F) -> Self::Output {
         let Matrix(s) = self;
         let Matrix(o) = other;
 
@@ -68,7 +78,7 @@ where
 {
     type Output = Matrix<V, { N }, { M }>;
 
-    default fn zip_with(self, other: &'b Matrix<U, { N }, { M }>, mut f: F) -> Self::Output {
+    fn zip_with(self, other: &'b Matrix<U, { N }, { M }>, mut f: F) -> Self::Output {
         let Matrix(s) = self;
         let Matrix(o) = other;
 
@@ -92,7 +102,7 @@ where
 {
     type Output = Matrix<V, { N }, { M }>;
 
-    default fn zip_with(self, other: Matrix<U, { N }, { M }>, mut f: F) -> Self::Output {
+    fn zip_with(self, other: Matrix<U, { N }, { M }>, mut f: F) -> Self::Output {
         let Matrix(s) = self;
         let Matrix(o) = other;
 
@@ -116,7 +126,7 @@ where
 {
     type Output = Matrix<V, { N }, { M }>;
 
-    default fn zip_with(self, other: &'b mut Matrix<U, { N }, { M }>, mut f: F) -> Self::Output {
+    fn zip_with(self, other: &'b mut Matrix<U, { N }, { M }>, mut f: F) -> Self::Output {
         let Matrix(s) = self;
         let Matrix(o) = other;
 
@@ -140,7 +150,7 @@ where
 {
     type Output = Matrix<V, { N }, { M }>;
 
-    default fn zip_with(self, other: &'b Matrix<U, { N }, { M }>, mut f: F) -> Self::Output {
+    fn zip_with(self, other: &'b Matrix<U, { N }, { M }>, mut f: F) -> Self::Output {
         let Matrix(s) = self;
         let Matrix(o) = other;
 
@@ -164,7 +174,7 @@ where
 {
     type Output = Matrix<V, { N }, { M }>;
 
-    default fn zip_with(self, other: Matrix<U, { N }, { M }>, mut f: F) -> Self::Output {
+    fn zip_with(self, other: Matrix<U, { N }, { M }>, mut f: F) -> Self::Output {
         let Matrix(s) = self;
         let Matrix(o) = other;
 
@@ -188,7 +198,7 @@ where
 {
     type Output = Matrix<V, { N }, { M }>;
 
-    default fn zip_with(self, other: &'b mut Matrix<U, { N }, { M }>, mut f: F) -> Self::Output {
+    fn zip_with(self, other: &'b mut Matrix<U, { N }, { M }>, mut f: F) -> Self::Output {
         let Matrix(s) = self;
         let Matrix(o) = other;
 
@@ -212,7 +222,7 @@ where
 {
     type Output = Matrix<V, { N }, { M }>;
 
-    default fn zip_with(self, other: &'b Matrix<U, { N }, { M }>, mut f: F) -> Self::Output {
+    fn zip_with(self, other: &'b Matrix<U, { N }, { M }>, mut f: F) -> Self::Output {
         let Matrix(s) = self;
         let Matrix(o) = other;
 
@@ -228,129 +238,3 @@ where
         unsafe { collect_mat(iter) }
     }
 }
-
-impl<T, U, F, const N: usize, const M: usize> ZipWith<Matrix<U, { N }, { M }>, F>
-    for Matrix<T, { N }, { M }>
-where
-    F: FnMut(T, U),
-{
-    default fn zip_with(self, other: Matrix<U, { N }, { M }>, f: F) -> Self::Output {
-        self.for_both(other, f);
-
-        assert_eq!(std::mem::size_of::<Self::Output>(), 0);
-
-        unsafe { std::mem::uninitialized() }
-    }
-}
-
-impl<'b, T, U, F, const N: usize, const M: usize> ZipWith<&'b mut Matrix<U, { N }, { M }>, F>
-    for Matrix<T, { N }, { M }>
-where
-    F: FnMut(T, &'b mut U),
-{
-    default fn zip_with(self, other: &'b mut Matrix<U, { N }, { M }>, f: F) -> Self::Output {
-        self.for_both(other, f);
-
-        assert_eq!(std::mem::size_of::<Self::Output>(), 0);
-
-        unsafe { std::mem::uninitialized() }
-    }
-}
-
-impl<'b, T, U, F, const N: usize, const M: usize> ZipWith<&'b Matrix<U, { N }, { M }>, F>
-    for Matrix<T, { N }, { M }>
-where
-    F: FnMut(T, &'b U),
-{
-    default fn zip_with(self, other: &'b Matrix<U, { N }, { M }>, f: F) -> Self::Output {
-        self.for_both(other, f);
-
-        assert_eq!(std::mem::size_of::<Self::Output>(), 0);
-
-        unsafe { std::mem::uninitialized() }
-    }
-}
-
-impl<'a, T, U, F, const N: usize, const M: usize> ZipWith<Matrix<U, { N }, { M }>, F>
-    for &'a mut Matrix<T, { N }, { M }>
-where
-    F: FnMut(&'a mut T, U),
-{
-    default fn zip_with(self, other: Matrix<U, { N }, { M }>, f: F) -> Self::Output {
-        self.for_both(other, f);
-
-        assert_eq!(std::mem::size_of::<Self::Output>(), 0);
-
-        unsafe { std::mem::uninitialized() }
-    }
-}
-
-impl<'a, 'b, T, U, F, const N: usize, const M: usize> ZipWith<&'b mut Matrix<U, { N }, { M }>, F>
-    for &'a mut Matrix<T, { N }, { M }>
-where
-    F: FnMut(&'a mut T, &'b mut U),
-{
-    default fn zip_with(self, other: &'b mut Matrix<U, { N }, { M }>, f: F) -> Self::Output {
-        self.for_both(other, f);
-
-        assert_eq!(std::mem::size_of::<Self::Output>(), 0);
-
-        unsafe { std::mem::uninitialized() }
-    }
-}
-
-impl<'a, 'b, T, U, F, const N: usize, const M: usize> ZipWith<&'b Matrix<U, { N }, { M }>, F>
-    for &'a mut Matrix<T, { N }, { M }>
-where
-    F: FnMut(&'a mut T, &'b U),
-{
-    default fn zip_with(self, other: &'b Matrix<U, { N }, { M }>, f: F) -> Self::Output {
-        self.for_both(other, f);
-
-        assert_eq!(std::mem::size_of::<Self::Output>(), 0);
-
-        unsafe { std::mem::uninitialized() }
-    }
-}
-
-impl<'a, T, U, F, const N: usize, const M: usize> ZipWith<Matrix<U, { N }, { M }>, F>
-    for &'a Matrix<T, { N }, { M }>
-where
-    F: FnMut(&'a T, U),
-{
-    default fn zip_with(self, other: Matrix<U, { N }, { M }>, f: F) -> Self::Output {
-        self.for_both(other, f);
-
-        assert_eq!(std::mem::size_of::<Self::Output>(), 0);
-
-        unsafe { std::mem::uninitialized() }
-    }
-}
-
-impl<'a, 'b, T, U, F, const N: usize, const M: usize> ZipWith<&'b mut Matrix<U, { N }, { M }>, F>
-    for &'a Matrix<T, { N }, { M }>
-where
-    F: FnMut(&'a T, &'b mut U),
-{
-    default fn zip_with(self, other: &'b mut Matrix<U, { N }, { M }>, f: F) -> Self::Output {
-        self.for_both(other, f);
-
-        assert_eq!(std::mem::size_of::<Self::Output>(), 0);
-
-        unsafe { std::mem::uninitialized() }
-    }
-}
-
-impl<'a, 'b, T, U, F, const N: usize, const M: usize> ZipWith<&'b Matrix<U, { N }, { M }>, F>
-    for &'a Matrix<T, { N }, { M }>
-where
-    F: FnMut(&'a T, &'b U),
-{
-    default fn zip_with(self, other: &'b Matrix<U, { N }, { M }>, f: F) -> Self::Output {
-        self.for_both(other, f);
-
-        assert_eq!(std::mem::size_of::<Self::Output>(), 0);
-
-        unsafe { std::mem::uninitialized() }
-    }
-}